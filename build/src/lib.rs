@@ -0,0 +1,226 @@
+//! # `include_data_build` - Write data files for `include_data`
+//!
+//! This crate writes files which can be read by the macros of the
+//! [`include_data`] crate. It is intended to be used from build scripts, so
+//! that data can be generated at build time and then included with
+//! `include_slice!` or `include_data!`.
+//!
+//! Those macros interpret multi-byte values according to the endianness of the
+//! compilation *target*, which need not match the machine running the build
+//! script. The writers in this crate therefore encode values explicitly: by
+//! default in the target endianness, read from the `CARGO_CFG_TARGET_ENDIAN`
+//! environment variable which cargo sets for build scripts. Files written
+//! with [`write_slice`], [`write_data`] or [`write_map`] are guaranteed to
+//! round-trip through `include_slice!`, `include_data!` and `include_map!`
+//! respectively.
+//!
+//! ```no_run
+//! // build.rs
+//! use include_data_build::{out_dir, write_slice};
+//!
+//! fn main() -> std::io::Result<()> {
+//!     let squares: Vec<u32> = (0..256).map(|i| i * i).collect();
+//!     write_slice(out_dir().join("squares"), &squares)
+//! }
+//! ```
+//!
+//! ```ignore
+//! // src/main.rs
+//! static SQUARES: &[u32] = include_data::include_slice!(u32, "squares", from = out_dir);
+//! ```
+//!
+//! ## Custom types
+//!
+//! Values are written with the [`Encode`] trait, which is implemented for
+//! primitive numbers and arrays. For custom types, implement it by encoding
+//! each field in order. This produces the correct layout for any type which
+//! can be derived `IncludeData`, since such types have no padding.
+//!
+//! ```
+//! # use include_data_build::{Encode, Endian};
+//! #[repr(C)]
+//! struct Point {
+//!     x: f32,
+//!     y: f32,
+//! }
+//!
+//! impl Encode for Point {
+//!     fn encode(&self, endian: Endian, out: &mut Vec<u8>) {
+//!         self.x.encode(endian, out);
+//!         self.y.encode(endian, out);
+//!     }
+//! }
+//! ```
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+pub use include_data::Endian;
+
+/// A value which can be written to a file readable by `include_data`.
+///
+/// Implementations must append exactly the bytes of the value as it would be
+/// laid out in memory on a target of the given endianness.
+///
+/// `usize` and `isize` do not implement this trait, since their size depends
+/// on the target.
+pub trait Encode {
+    /// Append the bytes of this value in the given endianness to `out`.
+    fn encode(&self, endian: Endian, out: &mut Vec<u8>);
+}
+
+macro_rules! impl_encode {
+    ($($ty:ty),* $(,)?) => {$(
+        impl Encode for $ty {
+            fn encode(&self, endian: Endian, out: &mut Vec<u8>) {
+                match endian {
+                    Endian::Little => out.extend_from_slice(&self.to_le_bytes()),
+                    Endian::Big => out.extend_from_slice(&self.to_be_bytes()),
+                }
+            }
+        }
+    )*};
+}
+
+impl_encode!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64);
+
+impl<T: Encode, const N: usize> Encode for [T; N] {
+    fn encode(&self, endian: Endian, out: &mut Vec<u8>) {
+        self.as_slice().encode(endian, out);
+    }
+}
+
+impl<T: Encode> Encode for [T] {
+    fn encode(&self, endian: Endian, out: &mut Vec<u8>) {
+        for value in self {
+            value.encode(endian, out);
+        }
+    }
+}
+
+/// The endianness of the compilation target.
+///
+/// In a build script, this is read from the `CARGO_CFG_TARGET_ENDIAN`
+/// environment variable. Elsewhere, the endianness of the current platform is
+/// returned.
+///
+/// # Panics
+///
+/// If `CARGO_CFG_TARGET_ENDIAN` is set to an unknown value.
+#[must_use]
+pub fn target_endian() -> Endian {
+    match std::env::var("CARGO_CFG_TARGET_ENDIAN").as_deref() {
+        Ok("little") => Endian::Little,
+        Ok("big") => Endian::Big,
+        Ok(other) => panic!("Unknown target endianness `{other}`"),
+        Err(_) => Endian::NATIVE,
+    }
+}
+
+/// The build script output directory, from the `OUT_DIR` environment
+/// variable.
+///
+/// # Panics
+///
+/// If `OUT_DIR` is not set, which means this is not running in a build
+/// script.
+#[must_use]
+pub fn out_dir() -> PathBuf {
+    std::env::var_os("OUT_DIR")
+        .expect("`OUT_DIR` is not set, so this is not running in a build script")
+        .into()
+}
+
+/// Encode a value as bytes in the given endianness.
+#[must_use]
+pub fn to_bytes<T: Encode + ?Sized>(value: &T, endian: Endian) -> Vec<u8> {
+    let mut out = Vec::new();
+    value.encode(endian, &mut out);
+    out
+}
+
+/// Write a slice to a file, for reading with `include_slice!(T, path)`.
+///
+/// Values are written in the endianness of the compilation target; see
+/// [`target_endian`].
+///
+/// # Errors
+///
+/// Any error from writing the file.
+pub fn write_slice<T: Encode>(path: impl AsRef<Path>, data: &[T]) -> io::Result<()> {
+    write_slice_with_endian(path, data, target_endian())
+}
+
+/// Write a value to a file, for reading with `include_data!(path)`.
+///
+/// Values are written in the endianness of the compilation target; see
+/// [`target_endian`].
+///
+/// # Errors
+///
+/// Any error from writing the file.
+pub fn write_data<T: Encode>(path: impl AsRef<Path>, value: &T) -> io::Result<()> {
+    write_data_with_endian(path, value, target_endian())
+}
+
+/// As [`write_slice`], but writing in the given endianness.
+///
+/// # Errors
+///
+/// Any error from writing the file.
+pub fn write_slice_with_endian<T: Encode>(
+    path: impl AsRef<Path>,
+    data: &[T],
+    endian: Endian,
+) -> io::Result<()> {
+    fs::write(path, to_bytes(data, endian))
+}
+
+/// As [`write_data`], but writing in the given endianness.
+///
+/// # Errors
+///
+/// Any error from writing the file.
+pub fn write_data_with_endian<T: Encode>(
+    path: impl AsRef<Path>,
+    value: &T,
+    endian: Endian,
+) -> io::Result<()> {
+    fs::write(path, to_bytes(value, endian))
+}
+
+/// Write key/value entries to a file, for reading with
+/// `include_map!(K, V, path)`.
+///
+/// Each entry is written as a record of its key immediately followed by its
+/// value, with no padding between them. Values are written in the endianness
+/// of the compilation target; see [`target_endian`].
+///
+/// # Errors
+///
+/// Any error from writing the file.
+pub fn write_map<K: Encode, V: Encode>(
+    path: impl AsRef<Path>,
+    entries: &[(K, V)],
+) -> io::Result<()> {
+    write_map_with_endian(path, entries, target_endian())
+}
+
+/// As [`write_map`], but writing in the given endianness.
+///
+/// # Errors
+///
+/// Any error from writing the file.
+pub fn write_map_with_endian<K: Encode, V: Encode>(
+    path: impl AsRef<Path>,
+    entries: &[(K, V)],
+    endian: Endian,
+) -> io::Result<()> {
+    let mut out = Vec::new();
+    for (key, value) in entries {
+        key.encode(endian, &mut out);
+        value.encode(endian, &mut out);
+    }
+    fs::write(path, out)
+}
@@ -0,0 +1,94 @@
+use include_data::{include_data, include_slice};
+use include_data_build::{
+    to_bytes, write_data, write_data_with_endian, write_map, write_map_with_endian, write_slice,
+    write_slice_with_endian, Encode, Endian,
+};
+
+static BINARY_32: &[u8] = include_bytes!("../../tests/test_data/binary_32");
+
+fn temp_file(name: &str) -> std::path::PathBuf {
+    std::path::Path::new(env!("CARGO_TARGET_TMPDIR")).join(name)
+}
+
+#[test]
+fn round_trip_slice() {
+    static U16S: &[u16] = include_slice!(u16, "../../tests/test_data/binary_32");
+    static F64S: &[f64] = include_slice!(f64, "../../tests/test_data/binary_32");
+
+    let path = temp_file("round_trip_u16");
+    write_slice(&path, U16S).unwrap();
+    assert_eq!(std::fs::read(&path).unwrap(), BINARY_32);
+
+    let path = temp_file("round_trip_f64");
+    write_slice(&path, F64S).unwrap();
+    assert_eq!(std::fs::read(&path).unwrap(), BINARY_32);
+}
+
+#[test]
+fn round_trip_data() {
+    static ARRAY: [[u32; 2]; 4] = include_data!("../../tests/test_data/binary_32");
+    static WORKSPACE: [[u32; 2]; 4] = include_data!("tests/test_data/binary_32", from = workspace);
+
+    let path = temp_file("round_trip_array");
+    write_data(&path, &ARRAY).unwrap();
+    assert_eq!(std::fs::read(&path).unwrap(), BINARY_32);
+    assert_eq!(WORKSPACE, ARRAY);
+}
+
+#[test]
+fn explicit_endian() {
+    let path = temp_file("big_endian");
+    write_slice_with_endian(&path, &[0x0001_u16, 0x0203], Endian::Big).unwrap();
+    assert_eq!(std::fs::read(&path).unwrap(), [0, 1, 2, 3]);
+
+    let path = temp_file("little_endian");
+    write_data_with_endian(&path, &0x0302_0100_u32, Endian::Little).unwrap();
+    assert_eq!(std::fs::read(&path).unwrap(), [0, 1, 2, 3]);
+}
+
+#[test]
+fn custom_type() {
+    struct Pair {
+        a: u8,
+        b: [i8; 3],
+    }
+
+    impl Encode for Pair {
+        fn encode(&self, endian: Endian, out: &mut Vec<u8>) {
+            self.a.encode(endian, out);
+            self.b.encode(endian, out);
+        }
+    }
+
+    let pairs = [Pair { a: 0, b: [1, 2, 3] }, Pair { a: 4, b: [5, 6, 7] }];
+    assert_eq!(to_bytes(&pairs, Endian::Big), BINARY_32[..8]);
+}
+
+#[test]
+fn map_records() {
+    static MAP: include_data::StaticMap<u16, [u8; 2]> =
+        include_data::include_map!(u16, [u8; 2], "../../tests/test_data/binary_32");
+
+    let entries: Vec<(u16, [u8; 2])> = (0..8)
+        .map(|i| {
+            (
+                u16::from_ne_bytes([4 * i, 4 * i + 1]),
+                [4 * i + 2, 4 * i + 3],
+            )
+        })
+        .collect();
+
+    let path = temp_file("map_native");
+    write_map_with_endian(&path, &entries, Endian::NATIVE).unwrap();
+    assert_eq!(std::fs::read(&path).unwrap(), BINARY_32);
+    for (key, value) in &entries {
+        assert_eq!(MAP.get(key), Some(value));
+    }
+
+    let path = temp_file("map_target");
+    write_map(&path, &[(0x0001_u16, 0x0203_u16)]).unwrap();
+    assert_eq!(
+        std::fs::read(&path).unwrap(),
+        to_bytes(&[0x0001_u16, 0x0203], Endian::NATIVE)
+    );
+}
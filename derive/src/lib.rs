@@ -0,0 +1,194 @@
+//! Derive macros for the `include_data` crate.
+//!
+//! These are re-exported by `include_data` (with the `derive` feature), which
+//! documents them. Generated code refers to `::include_data`, so that crate
+//! must be a direct dependency.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{quote, quote_spanned};
+use syn::spanned::Spanned;
+use syn::{parse_macro_input, Data, DeriveInput, Fields};
+
+/// Implementation of `include_data::IncludeData`.
+#[proc_macro_derive(IncludeData)]
+pub fn derive_include_data(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    match expand_include_data(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+fn expand_include_data(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        Data::Enum(data) => {
+            return Err(syn::Error::new(
+                data.enum_token.span,
+                "`IncludeData` cannot be derived for enums",
+            ))
+        }
+        Data::Union(data) => {
+            return Err(syn::Error::new(
+                data.union_token.span,
+                "`IncludeData` cannot be derived for unions",
+            ))
+        }
+    };
+
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new(
+            input.generics.span(),
+            "`IncludeData` cannot be derived for generic types",
+        ));
+    }
+
+    check_repr(input)?;
+
+    let field_types: Vec<_> = match fields {
+        Fields::Named(fields) => fields.named.iter().map(|f| &f.ty).collect(),
+        Fields::Unnamed(fields) => fields.unnamed.iter().map(|f| &f.ty).collect(),
+        Fields::Unit => Vec::new(),
+    };
+
+    let field_checks = field_types.iter().map(|ty| {
+        quote_spanned! {ty.span()=>
+            assert_pod::<#ty>();
+        }
+    });
+    let padding_message = format!("`{name}` contains padding bytes, so cannot be `IncludeData`");
+
+    Ok(quote! {
+        const _: () = {
+            #[allow(dead_code)]
+            fn assert_fields_pod() {
+                fn assert_pod<T: ::include_data::bytemuck::Pod>() {}
+                #(#field_checks)*
+            }
+
+            assert!(
+                ::core::mem::size_of::<#name>()
+                    == 0 #(+ ::core::mem::size_of::<#field_types>())*,
+                #padding_message,
+            );
+        };
+
+        // Safety: the type is `repr(C)` or `repr(transparent)`, all of its
+        // fields are `Pod` (and therefore `Zeroable`), and it has no padding,
+        // all of which are checked above.
+        unsafe impl ::include_data::bytemuck::Zeroable for #name {}
+        unsafe impl ::include_data::bytemuck::Pod for #name {}
+    })
+}
+
+/// Implementation of `include_data::LayoutFingerprint`.
+#[proc_macro_derive(LayoutFingerprint)]
+pub fn derive_layout_fingerprint(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    match expand_layout_fingerprint(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+fn expand_layout_fingerprint(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        Data::Enum(data) => {
+            return Err(syn::Error::new(
+                data.enum_token.span,
+                "`LayoutFingerprint` cannot be derived for enums",
+            ))
+        }
+        Data::Union(data) => {
+            return Err(syn::Error::new(
+                data.union_token.span,
+                "`LayoutFingerprint` cannot be derived for unions",
+            ))
+        }
+    };
+
+    // Field offsets are computed in `const` contexts, which is not possible
+    // for generic types on stable Rust.
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new(
+            input.generics.span(),
+            "`LayoutFingerprint` cannot be derived for generic types",
+        ));
+    }
+
+    let members: Vec<(TokenStream2, &syn::Type)> = match fields {
+        Fields::Named(fields) => fields
+            .named
+            .iter()
+            .map(|f| {
+                let ident = f.ident.as_ref().expect("named fields have identifiers");
+                (quote!(#ident), &f.ty)
+            })
+            .collect(),
+        Fields::Unnamed(fields) => fields
+            .unnamed
+            .iter()
+            .enumerate()
+            .map(|(i, f)| {
+                let index = syn::Index::from(i);
+                (quote!(#index), &f.ty)
+            })
+            .collect(),
+        Fields::Unit => Vec::new(),
+    };
+
+    let field_count = members.len();
+    let field_hashes = members.iter().map(|(member, ty)| {
+        quote_spanned! {ty.span()=>
+            .write_usize(::include_data::__offset_of!(Self, #member))
+            .write_u64(<#ty as ::include_data::LayoutFingerprint>::FINGERPRINT)
+        }
+    });
+
+    Ok(quote! {
+        impl ::include_data::LayoutFingerprint for #name {
+            const FINGERPRINT: u64 = ::include_data::FingerprintHasher::new()
+                .write_str("struct")
+                .write_usize(::core::mem::size_of::<Self>())
+                .write_usize(::core::mem::align_of::<Self>())
+                .write_usize(#field_count)
+                #(#field_hashes)*
+                .finish();
+        }
+    })
+}
+
+/// Error unless the type is `repr(C)` or `repr(transparent)`.
+fn check_repr(input: &DeriveInput) -> syn::Result<()> {
+    let mut has_stable_layout = false;
+    for attr in input.attrs.iter().filter(|a| a.path().is_ident("repr")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("C") || meta.path.is_ident("transparent") {
+                has_stable_layout = true;
+            }
+            // Skip the arguments of e.g. `align(8)` or `packed(2)`
+            if meta.input.peek(syn::token::Paren) {
+                let _content;
+                syn::parenthesized!(_content in meta.input);
+            }
+            Ok(())
+        })?;
+    }
+
+    if has_stable_layout {
+        Ok(())
+    } else {
+        Err(syn::Error::new(
+            input.ident.span(),
+            "`IncludeData` can only be derived for `#[repr(C)]` or `#[repr(transparent)]` types",
+        ))
+    }
+}
@@ -0,0 +1,6 @@
+fn main() {
+    // `workspace_dir_impl` reads this variable while expanding, which the
+    // compiler cannot see. Rebuilding this crate when it changes also rebuilds
+    // every crate using the macro, so that the new root is picked up.
+    println!("cargo:rerun-if-env-changed=CARGO_WORKSPACE_DIR");
+}
@@ -0,0 +1,373 @@
+//! Procedural macros supporting the `include_data` crate.
+//!
+//! Nothing in this crate should be used directly: the macros here are
+//! re-exported (and wrapped) by `include_data`, which documents them.
+
+#![cfg_attr(feature = "nightly", feature(proc_macro_tracked_path))]
+
+use std::path::{Path, PathBuf};
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2, TokenTree};
+use quote::{format_ident, quote};
+use syn::parse::{Parse, ParseStream};
+use syn::{parse_macro_input, Attribute, Ident, LitStr, Token, Type, Visibility};
+
+/// Implementation of `include_data::include_glob`.
+///
+/// Expects the input `$crate; <attrs> <vis> enum <Name>: <Type> = "<pattern>";`,
+/// where `$crate` is passed through from the wrapping `macro_rules` macro.
+#[proc_macro]
+pub fn include_glob_impl(input: TokenStream) -> TokenStream {
+    let GlobInput {
+        krate,
+        attrs,
+        vis,
+        name,
+        target_ty,
+        pattern,
+    } = parse_macro_input!(input as GlobInput);
+
+    match expand_glob(&krate, &attrs, &vis, &name, &target_ty, &pattern) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+struct GlobInput {
+    krate: TokenStream2,
+    attrs: Vec<Attribute>,
+    vis: Visibility,
+    name: Ident,
+    target_ty: Type,
+    pattern: LitStr,
+}
+
+impl Parse for GlobInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let krate = parse_crate_path(input)?;
+        let attrs = input.call(Attribute::parse_outer)?;
+        let vis = input.parse()?;
+        input.parse::<Token![enum]>()?;
+        let name = input.parse()?;
+        input.parse::<Token![:]>()?;
+        let target_ty = input.parse()?;
+        input.parse::<Token![=]>()?;
+        let pattern = input.parse()?;
+        if input.peek(Token![;]) {
+            input.parse::<Token![;]>()?;
+        }
+
+        Ok(Self {
+            krate,
+            attrs,
+            vis,
+            name,
+            target_ty,
+            pattern,
+        })
+    }
+}
+
+/// Collect the (opaque) tokens of the `$crate` path up to the first `;`.
+fn parse_crate_path(input: ParseStream) -> syn::Result<TokenStream2> {
+    let mut krate = TokenStream2::new();
+    while !input.peek(Token![;]) {
+        krate.extend([input.parse::<TokenTree>()?]);
+    }
+    input.parse::<Token![;]>()?;
+    Ok(krate)
+}
+
+fn expand_glob(
+    krate: &TokenStream2,
+    attrs: &[Attribute],
+    vis: &Visibility,
+    name: &Ident,
+    target_ty: &Type,
+    pattern: &LitStr,
+) -> syn::Result<TokenStream2> {
+    let root = manifest_dir(pattern.span())?;
+    let files = glob_files(&root, pattern)?;
+
+    let mut variants: Vec<(Ident, &str)> = Vec::with_capacity(files.len());
+    for (relative, _) in &files {
+        let variant = variant_name(relative, pattern.span())?;
+        if let Some((_, other)) = variants.iter().find(|(v, _)| *v == variant) {
+            return Err(syn::Error::new(
+                pattern.span(),
+                format!("files `{other}` and `{relative}` both map to the variant `{variant}`"),
+            ));
+        }
+        variants.push((variant, relative));
+    }
+
+    let count = variants.len();
+    let variant_idents: Vec<&Ident> = variants.iter().map(|(v, _)| v).collect();
+    let relative_paths: Vec<&str> = variants.iter().map(|(_, p)| *p).collect();
+    let absolute_paths: Vec<&str> = files.iter().map(|(_, abs)| abs.as_str()).collect();
+
+    Ok(quote! {
+        #(#attrs)*
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        #vis enum #name {
+            #(#variant_idents,)*
+        }
+
+        impl #name {
+            /// Every variant, in order of file path.
+            pub const ALL: [Self; #count] = [#(Self::#variant_idents,)*];
+
+            #[allow(clippy::modulo_one)]
+            const DATA: [&'static [#target_ty]; #count] = [
+                #(#krate::__include_slice_const!(#target_ty, #absolute_paths),)*
+            ];
+
+            /// The contents of the file for this variant.
+            pub const fn get(self) -> &'static [#target_ty] {
+                Self::DATA[self as usize]
+            }
+
+            /// The path of the file for this variant, relative to the
+            /// crate's manifest directory.
+            pub const fn path(self) -> &'static str {
+                match self {
+                    #(Self::#variant_idents => #relative_paths,)*
+                }
+            }
+        }
+
+        // Force evaluation (and thus size checking) of every included file,
+        // even if `get` is never called.
+        const _: [&'static [#target_ty]; #count] = #name::DATA;
+    })
+}
+
+/// Implementation of `include_data::file_exists`, and the fallback include
+/// macros.
+///
+/// Expects the input `"<path>", { <tokens> }, { <tokens> }` and expands to
+/// the first block if the file at `path` (relative to the crate's manifest
+/// directory) exists, or to the second otherwise.
+#[proc_macro]
+pub fn if_file_exists_impl(input: TokenStream) -> TokenStream {
+    let IfFileExistsInput {
+        path,
+        found,
+        missing,
+    } = parse_macro_input!(input as IfFileExistsInput);
+
+    match manifest_dir(path.span()) {
+        Ok(root) if root.join(path.value()).is_file() => TokenStream2::from(found).into(),
+        Ok(root) => {
+            track_path(&root.join(path.value()));
+            TokenStream2::from(missing).into()
+        }
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+/// Ask the compiler to rebuild the crate if `path` changes, including if it is
+/// created.
+///
+/// Files included by the expansion are tracked by its `include_bytes!`, so
+/// this is only needed for missing files and for files which are only read by
+/// the macro itself. It requires an unstable API, and so does nothing unless
+/// the `nightly` feature is enabled.
+fn track_path(path: &Path) {
+    #[cfg(feature = "nightly")]
+    if let Some(path) = path.to_str() {
+        proc_macro::tracked::path(path);
+    }
+    #[cfg(not(feature = "nightly"))]
+    let _ = path;
+}
+
+struct IfFileExistsInput {
+    path: LitStr,
+    found: TokenTree,
+    missing: TokenTree,
+}
+
+impl Parse for IfFileExistsInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let path = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let found = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let missing = input.parse()?;
+        if input.peek(Token![,]) {
+            input.parse::<Token![,]>()?;
+        }
+
+        Ok(Self {
+            path,
+            found,
+            missing,
+        })
+    }
+}
+
+/// Implementation of the `from = workspace` path root.
+///
+/// Expects no input and expands to the workspace root directory: the
+/// `CARGO_WORKSPACE_DIR` environment variable if it is set, or else the nearest
+/// ancestor of the crate's manifest directory whose `Cargo.toml` has a
+/// `[workspace]` table, or else the manifest directory itself (as a package
+/// outside any workspace is its own workspace).
+#[proc_macro]
+pub fn workspace_dir_impl(input: TokenStream) -> TokenStream {
+    let input = TokenStream2::from(input);
+    if !input.is_empty() {
+        return syn::Error::new_spanned(input, "expected no arguments")
+            .to_compile_error()
+            .into();
+    }
+
+    // The expansion must be a string literal for `concat!`, so the variable
+    // cannot be read with `option_env!`. Instead, the build script of this
+    // crate rebuilds it (and so every crate using it) when the variable
+    // changes, and `env!` tracks it when it is set.
+    if std::env::var_os("CARGO_WORKSPACE_DIR").is_some() {
+        return quote!(::core::env!("CARGO_WORKSPACE_DIR")).into();
+    }
+
+    let span = Span::call_site();
+    let result = manifest_dir(span).and_then(|manifest| {
+        let root = manifest
+            .ancestors()
+            .find(|dir| {
+                // Adding or removing a `[workspace]` table moves the root.
+                let manifest = dir.join("Cargo.toml");
+                track_path(&manifest);
+                is_workspace_root(&manifest)
+            })
+            .unwrap_or(&manifest);
+        root.to_str()
+            .map(|root| LitStr::new(root, span))
+            .ok_or_else(|| syn::Error::new(span, "workspace directory is not valid UTF-8"))
+    });
+
+    match result {
+        Ok(root) => quote!(#root).into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+/// Implementation of paths relative to the calling file for values resolved at
+/// runtime, such as the hot reloading handles of `include_data`.
+///
+/// Expects no input and expands to the compiler's working directory, which
+/// relative `file!()` paths are relative to.
+#[proc_macro]
+pub fn current_dir_impl(input: TokenStream) -> TokenStream {
+    let input = TokenStream2::from(input);
+    if !input.is_empty() {
+        return syn::Error::new_spanned(input, "expected no arguments")
+            .to_compile_error()
+            .into();
+    }
+
+    let span = Span::call_site();
+    let result = std::env::current_dir()
+        .map_err(|err| syn::Error::new(span, format!("cannot read working directory: {err}")))
+        .and_then(|dir| {
+            dir.to_str()
+                .map(|dir| LitStr::new(dir, span))
+                .ok_or_else(|| syn::Error::new(span, "working directory is not valid UTF-8"))
+        });
+
+    match result {
+        Ok(dir) => quote!(#dir).into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+/// Whether the `Cargo.toml` at `manifest` exists and has a `[workspace]`
+/// table.
+fn is_workspace_root(manifest: &Path) -> bool {
+    std::fs::read_to_string(manifest).map_or(false, |manifest| {
+        manifest.lines().any(|line| {
+            let line = line.split('#').next().unwrap_or_default().trim();
+            line == "[workspace]" || line.starts_with("[workspace.")
+        })
+    })
+}
+
+fn manifest_dir(span: Span) -> syn::Result<PathBuf> {
+    std::env::var_os("CARGO_MANIFEST_DIR")
+        .map(PathBuf::from)
+        .ok_or_else(|| syn::Error::new(span, "`CARGO_MANIFEST_DIR` is not set"))
+}
+
+/// All files matching `pattern` (relative to `root`), sorted, as pairs of
+/// (relative path with `/` separators, absolute path).
+fn glob_files(root: &Path, pattern: &LitStr) -> syn::Result<Vec<(String, String)>> {
+    let span = pattern.span();
+    let full_pattern = root.join(pattern.value());
+    let full_pattern = full_pattern
+        .to_str()
+        .ok_or_else(|| syn::Error::new(span, "glob pattern is not valid UTF-8"))?;
+
+    let paths = glob::glob(full_pattern)
+        .map_err(|err| syn::Error::new(span, format!("invalid glob pattern: {err}")))?;
+
+    let mut files = Vec::new();
+    for path in paths {
+        let path = path.map_err(|err| syn::Error::new(span, err.to_string()))?;
+        if !path.is_file() {
+            continue;
+        }
+
+        let relative = path
+            .strip_prefix(root)
+            .unwrap_or(&path)
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+        let absolute = path
+            .to_str()
+            .ok_or_else(|| syn::Error::new(span, format!("path `{relative}` is not valid UTF-8")))?
+            .to_owned();
+        files.push((relative, absolute));
+    }
+
+    if files.is_empty() {
+        return Err(syn::Error::new(
+            span,
+            format!("glob pattern `{}` did not match any files", pattern.value()),
+        ));
+    }
+
+    files.sort();
+    Ok(files)
+}
+
+/// Convert the stem of a file name to an `UpperCamelCase` identifier, e.g.
+/// `assets/some_file-name.bin` to `SomeFileName`.
+fn variant_name(relative: &str, span: Span) -> syn::Result<Ident> {
+    let file_name = relative.rsplit('/').next().unwrap_or(relative);
+    let stem = match file_name.split_once('.') {
+        Some((stem, _)) if !stem.is_empty() => stem,
+        _ => file_name,
+    };
+
+    let mut name = String::with_capacity(stem.len());
+    for word in stem.split(|c: char| !c.is_ascii_alphanumeric()) {
+        let mut chars = word.chars();
+        if let Some(first) = chars.next() {
+            name.push(first.to_ascii_uppercase());
+            name.extend(chars);
+        }
+    }
+
+    if !name.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        return Err(syn::Error::new(
+            span,
+            format!("cannot derive an enum variant name from the file `{relative}`"),
+        ));
+    }
+
+    Ok(format_ident!("{}", name, span = span))
+}
@@ -0,0 +1,463 @@
+use core::fmt;
+use core::mem::size_of;
+
+use ndarray::{ArrayView, Dim, Dimension, Ix, ShapeBuilder};
+
+use crate::AlignedAs;
+
+/// An N-dimensional array of included data, usually included with
+/// [`include_ndarray`](crate::include_ndarray) or
+/// [`include_npy`](crate::include_npy), which can be viewed as an `ndarray`
+/// array without copying.
+///
+/// Unlike an [`ArrayView`], this can be created in `const` contexts, so can be
+/// assigned to a `static`. [`StaticArray::view`] then gives an
+/// `ArrayView<'static, T, D>` of the data, such as an `ArrayView2<'static, T>`
+/// for `StaticArray<T, 2>`.
+#[allow(clippy::module_name_repetitions)]
+pub struct StaticArray<T: 'static, const D: usize> {
+    data: &'static [T],
+    shape: [usize; D],
+    fortran_order: bool,
+}
+
+impl<T, const D: usize> StaticArray<T, D> {
+    /// The array of the given shape, in row-major order.
+    ///
+    /// # Panics
+    ///
+    /// If the number of elements of the shape is not the length of `data`.
+    #[must_use]
+    pub const fn new(data: &'static [T], shape: [usize; D]) -> Self {
+        assert!(
+            shape_matches(&shape, data.len()),
+            "Array shape does not match the number of included elements",
+        );
+        Self {
+            data,
+            shape,
+            fortran_order: false,
+        }
+    }
+
+    /// The array of the given shape, in column-major order.
+    ///
+    /// # Panics
+    ///
+    /// If the number of elements of the shape is not the length of `data`.
+    #[must_use]
+    pub const fn new_fortran_order(data: &'static [T], shape: [usize; D]) -> Self {
+        let mut array = Self::new(data, shape);
+        array.fortran_order = true;
+        array
+    }
+
+    /// The elements, in the order they are stored.
+    #[must_use]
+    pub const fn as_slice(&self) -> &'static [T] {
+        self.data
+    }
+
+    /// The length of each axis.
+    #[must_use]
+    pub const fn shape(&self) -> [usize; D] {
+        self.shape
+    }
+
+    /// Whether the elements are stored in column-major order, rather than
+    /// row-major order.
+    #[must_use]
+    pub const fn is_fortran_order(&self) -> bool {
+        self.fortran_order
+    }
+
+    /// The total number of elements.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Whether there are no elements.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+}
+
+impl<T: NpyElement, const D: usize> StaticArray<T, D> {
+    /// Create a `StaticArray` from the data of an included `.npy` file,
+    /// checking the header of the whole `file`.
+    ///
+    /// `data` must be the part of `file` after the header (as given by
+    /// [`npy_data_start`]), copied so that it is aligned for `T`.
+    #[doc(hidden)]
+    #[must_use]
+    pub const fn from_npy(file: &'static [u8], data: &'static AlignedAs<T, [u8]>) -> Self {
+        let header = parse_npy(file);
+        assert!(
+            descr_eq(file, header.descr, T::DESCR),
+            "Included .npy data type does not match the target type",
+        );
+        assert!(
+            header.ndim == D,
+            "Included .npy array has a different number of dimensions than the target",
+        );
+
+        let mut shape = [0; D];
+        let mut i = 0;
+        while i < D {
+            shape[i] = header.shape[i];
+            i += 1;
+        }
+        let bytes = &data.bytes;
+        let len = bytes.len() / size_of::<T>();
+        assert!(
+            bytes.len() % size_of::<T>() == 0 && shape_matches(&shape, len),
+            "Included .npy data size does not match its shape",
+        );
+
+        // Safety: `AlignedAs` aligns the bytes for `T`, which is
+        // `AnyBitPattern`.
+        let data = unsafe { core::slice::from_raw_parts(bytes.as_ptr().cast(), len) };
+        Self {
+            data,
+            shape,
+            fortran_order: header.fortran_order,
+        }
+    }
+}
+
+impl<T, const D: usize> StaticArray<T, D>
+where
+    Dim<[Ix; D]>: Dimension,
+    [Ix; D]: ShapeBuilder<Dim = Dim<[Ix; D]>>,
+{
+    /// A view of the array, without copying.
+    #[must_use]
+    pub fn view(&self) -> ArrayView<'static, T, Dim<[Ix; D]>> {
+        ArrayView::from_shape(self.shape.set_f(self.fortran_order), self.data)
+            .expect("shape is checked when the array is created")
+    }
+}
+
+impl<T, const D: usize> Clone for StaticArray<T, D> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, const D: usize> Copy for StaticArray<T, D> {}
+
+impl<T: fmt::Debug, const D: usize> fmt::Debug for StaticArray<T, D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StaticArray")
+            .field("shape", &self.shape)
+            .field("fortran_order", &self.fortran_order)
+            .field("data", &self.data)
+            .finish()
+    }
+}
+
+/// Whether the product of the lengths in `shape` is `len`, without overflow.
+const fn shape_matches(shape: &[usize], len: usize) -> bool {
+    let mut count: usize = 1;
+    let mut i = 0;
+    while i < shape.len() {
+        count = match count.checked_mul(shape[i]) {
+            Some(count) => count,
+            None => return false,
+        };
+        i += 1;
+    }
+    count == len
+}
+
+/// Element types of `.npy` files, which can be included with
+/// [`include_npy`](crate::include_npy).
+///
+/// Implemented for primitive integers and floats, for which the data type in
+/// the file must be of the endianness of the compilation target.
+pub trait NpyElement: bytemuck::AnyBitPattern {
+    /// The `numpy` array-protocol type string, such as `<f4`.
+    #[doc(hidden)]
+    const DESCR: &'static str;
+}
+
+macro_rules! impl_npy_element {
+    ($($ty:ty => $little:literal, $big:literal;)*) => {
+        $(
+            impl NpyElement for $ty {
+                #[cfg(target_endian = "little")]
+                const DESCR: &'static str = $little;
+                #[cfg(target_endian = "big")]
+                const DESCR: &'static str = $big;
+            }
+        )*
+    };
+}
+
+impl_npy_element! {
+    u8 => "|u1", "|u1";
+    i8 => "|i1", "|i1";
+    u16 => "<u2", ">u2";
+    i16 => "<i2", ">i2";
+    u32 => "<u4", ">u4";
+    i32 => "<i4", ">i4";
+    u64 => "<u8", ">u8";
+    i64 => "<i8", ">i8";
+    f32 => "<f4", ">f4";
+    f64 => "<f8", ">f8";
+}
+
+#[cfg(feature = "half")]
+impl_npy_element! {
+    half::f16 => "<f2", ">f2";
+}
+
+/// The most dimensions of an included `.npy` array.
+const MAX_NDIM: usize = 32;
+
+/// The parts of an `.npy` header needed to include its data.
+#[derive(Clone, Copy)]
+struct NpyHeader {
+    /// The start and length of the type string.
+    descr: (usize, usize),
+    fortran_order: bool,
+    ndim: usize,
+    shape: [usize; MAX_NDIM],
+    data_start: usize,
+}
+
+/// The number of dimensions of the array in an included `.npy` file.
+#[doc(hidden)]
+#[must_use]
+pub const fn npy_ndim(file: &'static [u8]) -> usize {
+    parse_npy(file).ndim
+}
+
+/// The offset of the data after the header of an included `.npy` file.
+#[doc(hidden)]
+#[must_use]
+pub const fn npy_data_start(file: &'static [u8]) -> usize {
+    parse_npy(file).data_start
+}
+
+/// Parse the header of an `.npy` file, as described at
+/// <https://numpy.org/doc/stable/reference/generated/numpy.lib.format.html>.
+const fn parse_npy(file: &'static [u8]) -> NpyHeader {
+    assert!(
+        file.len() >= 10 && starts_with(file, 0, b"\x93NUMPY"),
+        "Included file is not an .npy file",
+    );
+    let (header_start, header_len) = match file[6] {
+        1 => (10, file[8] as usize | (file[9] as usize) << 8),
+        2 | 3 => {
+            assert!(file.len() >= 12, "Included .npy header is truncated");
+            (
+                12,
+                file[8] as usize
+                    | (file[9] as usize) << 8
+                    | (file[10] as usize) << 16
+                    | (file[11] as usize) << 24,
+            )
+        }
+        _ => panic!("Included .npy file has an unsupported format version"),
+    };
+    let end = header_start + header_len;
+    assert!(end <= file.len(), "Included .npy header is truncated");
+
+    // The value of `'descr'` is a quoted string.
+    let mut pos = value_start(file, header_start, end, b"'descr'");
+    let quote = file[pos];
+    assert!(
+        quote == b'\'' || quote == b'"',
+        "Included .npy header has an unsupported data type",
+    );
+    pos += 1;
+    let descr_start = pos;
+    while pos < end && file[pos] != quote {
+        pos += 1;
+    }
+    assert!(pos < end, "Included .npy header is truncated");
+    let descr = (descr_start, pos - descr_start);
+
+    let pos = value_start(file, header_start, end, b"'fortran_order'");
+    let fortran_order = if starts_with(file, pos, b"True") {
+        true
+    } else if starts_with(file, pos, b"False") {
+        false
+    } else {
+        panic!("Included .npy header has an invalid fortran_order");
+    };
+
+    // The value of `'shape'` is a tuple of integers, such as `()`, `(3,)` or
+    // `(3, 4)`.
+    let mut pos = value_start(file, header_start, end, b"'shape'");
+    assert!(
+        file[pos] == b'(',
+        "Included .npy header has an invalid shape"
+    );
+    pos += 1;
+    let mut shape = [0; MAX_NDIM];
+    let mut ndim = 0;
+    loop {
+        while pos < end && matches!(file[pos], b' ' | b',') {
+            pos += 1;
+        }
+        assert!(pos < end, "Included .npy header has an invalid shape");
+        if file[pos] == b')' {
+            break;
+        }
+        assert!(
+            ndim < MAX_NDIM,
+            "Included .npy array has too many dimensions",
+        );
+        assert!(
+            file[pos].is_ascii_digit(),
+            "Included .npy header has an invalid shape",
+        );
+        let mut length: usize = 0;
+        while pos < end && file[pos].is_ascii_digit() {
+            length = match length.checked_mul(10) {
+                Some(length) => length + (file[pos] - b'0') as usize,
+                None => panic!("Included .npy header has an invalid shape"),
+            };
+            pos += 1;
+        }
+        shape[ndim] = length;
+        ndim += 1;
+    }
+
+    NpyHeader {
+        descr,
+        fortran_order,
+        ndim,
+        shape,
+        data_start: end,
+    }
+}
+
+/// The position of the value of `key` in the header `file[start..end]`.
+const fn value_start(file: &[u8], start: usize, end: usize, key: &[u8]) -> usize {
+    let mut pos = start;
+    while pos + key.len() <= end && !starts_with(file, pos, key) {
+        pos += 1;
+    }
+    assert!(
+        pos + key.len() <= end,
+        "Included .npy header is missing a required key",
+    );
+    pos += key.len();
+    while pos < end && matches!(file[pos], b' ' | b':') {
+        pos += 1;
+    }
+    assert!(pos < end, "Included .npy header is truncated");
+    pos
+}
+
+/// Whether `bytes[at..]` starts with `prefix`.
+const fn starts_with(bytes: &[u8], at: usize, prefix: &[u8]) -> bool {
+    if bytes.len() < at + prefix.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < prefix.len() {
+        if bytes[at + i] != prefix[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Whether the type string at `descr` in `file` is `expected`.
+const fn descr_eq(file: &[u8], (start, len): (usize, usize), expected: &str) -> bool {
+    len == expected.len() && starts_with(file, start, expected.as_bytes())
+}
+
+/// Include a file as a [`StaticArray`] of the given shape, which can be
+/// viewed as an `ndarray` array without copying.
+///
+/// `include_ndarray!(T, [d0, d1, ...], path)` includes the file at `path` as
+/// with [`include_slice`](crate::include_slice), as an array with the given
+/// length of each axis in row-major order. The number of axes is the `D` of
+/// the resulting `StaticArray<T, D>`. A compiler error will be thrown if the
+/// number of elements in the file does not match the shape.
+///
+/// This can assign to both `static` and `const` variables, and paths are
+/// interpreted by [`core::include_bytes`], or resolved with a `from = ...`
+/// argument as for [`include_data`](crate::include_data#path-roots).
+///
+/// # Example
+///
+/// ```
+/// # use include_data::{include_ndarray, StaticArray};
+/// static GRID: StaticArray<u16, 2> = include_ndarray!(u16, [4, 4], "../tests/test_data/binary_32");
+///
+/// let view = GRID.view();
+/// assert_eq!(view.dim(), (4, 4));
+/// assert_eq!(view[[1, 0]], u16::from_ne_bytes([8, 9]));
+/// ```
+#[macro_export]
+macro_rules! include_ndarray {
+    ($target_ty:ty, [$($dim:expr),* $(,)?], $file:expr $(,)?) => {{
+        const SLICE: &[$target_ty] = $crate::__include_slice_const!($target_ty, $file);
+        const ARRAY: $crate::StaticArray<$target_ty, { [$($dim),*].len() }> =
+            $crate::StaticArray::new(SLICE, [$($dim),*]);
+
+        ARRAY
+    }};
+    ($target_ty:ty, [$($dim:expr),* $(,)?], $file:expr, from = $root:ident $(($var:literal))? $(,)?) => {
+        $crate::include_ndarray!($target_ty, [$($dim),*], $crate::__resolve_path!($root $(($var))?, $file))
+    };
+}
+
+/// Include an `.npy` file, as written by `numpy.save`, as a [`StaticArray`],
+/// which can be viewed as an `ndarray` array without copying.
+///
+/// `include_npy!(T, path)` reads the shape and memory order of the array from
+/// the header of the file at `path`, which is checked at compile time. A
+/// compiler error will be thrown if the data type in the file does not match
+/// `T` (which must implement [`NpyElement`], and be stored in the endianness
+/// of the compilation target), or if the data does not match the shape. The
+/// data after the header is copied into its own aligned constant, so only the
+/// data is included in the executable, not the header.
+///
+/// The number of dimensions in the file must also match the `D` of the
+/// `StaticArray<T, D>` which the result is assigned to, or there will be a
+/// type mismatch.
+///
+/// This can assign to both `static` and `const` variables, and paths are
+/// interpreted by [`core::include_bytes`], or resolved with a `from = ...`
+/// argument as for [`include_data`](crate::include_data#path-roots).
+///
+/// # Example
+///
+/// ```
+/// # use include_data::{include_npy, StaticArray};
+/// // Saved with `numpy.save("matrix_f32_2x3.npy", numpy.arange(6, dtype="<f4").reshape(2, 3))`.
+/// static MATRIX: StaticArray<f32, 2> = include_npy!(f32, "../tests/test_data/matrix_f32_2x3.npy");
+///
+/// let view = MATRIX.view();
+/// assert_eq!(view.dim(), (2, 3));
+/// assert_eq!(view[[1, 2]], 5.0);
+/// ```
+#[macro_export]
+macro_rules! include_npy {
+    ($target_ty:ty, $file:expr $(,)?) => {{
+        const FILE: &[u8] = ::core::include_bytes!($file);
+        const NDIM: usize = $crate::npy_ndim(FILE);
+        const DATA_START: usize = $crate::npy_data_start(FILE);
+        const DATA: &$crate::AlignedAs<$target_ty, [u8]> = &$crate::AlignedAs {
+            _align: [],
+            bytes: $crate::copy_bytes::<{ FILE.len() - DATA_START }>(FILE, DATA_START),
+        };
+        const ARRAY: $crate::StaticArray<$target_ty, NDIM> =
+            $crate::StaticArray::from_npy(FILE, DATA);
+
+        ARRAY
+    }};
+    ($target_ty:ty, $file:expr, from = $root:ident $(($var:literal))? $(,)?) => {
+        $crate::include_npy!($target_ty, $crate::__resolve_path!($root $(($var))?, $file))
+    };
+}
@@ -0,0 +1,348 @@
+use core::fmt;
+use core::iter::FusedIterator;
+
+/// The order of bits within each byte of a [`BitSlice`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum BitOrder {
+    /// Bit `i` is the `i % 8`th least significant bit of byte `i / 8`.
+    #[default]
+    LsbFirst,
+    /// Bit `i` is the `i % 8`th most significant bit of byte `i / 8`.
+    MsbFirst,
+}
+
+/// A read-only slice of bits, packed eight to a byte, usually included with
+/// [`include_bits`](crate::include_bits).
+///
+/// Any bits in the last byte beyond [`BitSlice::len`] are ignored.
+#[derive(Clone, Copy)]
+pub struct BitSlice {
+    bytes: &'static [u8],
+    len: usize,
+    order: BitOrder,
+}
+
+impl BitSlice {
+    /// All of the bits of `bytes`, in the given order.
+    #[must_use]
+    pub const fn new(bytes: &'static [u8], order: BitOrder) -> Self {
+        Self::with_len(bytes, bytes.len() * 8, order)
+    }
+
+    /// The first `len` bits of `bytes`, in the given order.
+    ///
+    /// # Panics
+    ///
+    /// If `bytes` contains fewer than `len` bits.
+    #[must_use]
+    pub const fn with_len(bytes: &'static [u8], len: usize, order: BitOrder) -> Self {
+        assert!(len <= bytes.len() * 8, "Too few bytes for bit length");
+        Self { bytes, len, order }
+    }
+
+    /// Create a `BitSlice` from an included file, checking its length.
+    #[doc(hidden)]
+    #[must_use]
+    pub const fn from_included(file: &'static [u8], options: BitOptions) -> Self {
+        assert!(
+            file.len() >= options.header,
+            "Included file is too short for bit length header",
+        );
+        // Safety: `file` has at least `options.header` bytes.
+        let bytes = unsafe {
+            core::slice::from_raw_parts(
+                file.as_ptr().add(options.header),
+                file.len() - options.header,
+            )
+        };
+        let available = bytes.len() * 8;
+
+        let mut len = options.len;
+        if options.header > 0 {
+            let mut header: u64 = 0;
+            let mut i = 0;
+            while i < options.header {
+                let byte = file[i] as u64;
+                if cfg!(target_endian = "little") {
+                    header |= byte << (8 * i);
+                } else {
+                    header = (header << 8) | byte;
+                }
+                i += 1;
+            }
+            assert!(
+                header <= available as u64,
+                "Included file size does not match bit length",
+            );
+            // Cannot truncate, since it is at most `available`.
+            #[allow(clippy::cast_possible_truncation)]
+            let header = header as usize;
+
+            if let Some(len) = len {
+                assert!(
+                    len == header,
+                    "Bit length argument does not match bit length header",
+                );
+            }
+            len = Some(header);
+        }
+
+        match len {
+            Some(len) => {
+                assert!(
+                    len <= available && available - len < 8,
+                    "Included file size does not match bit length",
+                );
+                Self::with_len(bytes, len, options.order)
+            }
+            None => Self::new(bytes, options.order),
+        }
+    }
+
+    /// The number of bits.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether there are no bits.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The order of bits within each byte.
+    #[must_use]
+    pub const fn order(&self) -> BitOrder {
+        self.order
+    }
+
+    /// The bytes containing the bits, including any unused bits at the end.
+    #[must_use]
+    pub const fn as_bytes(&self) -> &'static [u8] {
+        self.bytes
+    }
+
+    /// The bit at `index`, or `None` if it is out of bounds.
+    #[must_use]
+    pub const fn get(&self, index: usize) -> Option<bool> {
+        if index >= self.len {
+            return None;
+        }
+        let shift = match self.order {
+            BitOrder::LsbFirst => index % 8,
+            BitOrder::MsbFirst => 7 - index % 8,
+        };
+        Some(self.bytes[index / 8] >> shift & 1 == 1)
+    }
+
+    /// Iterate over the bits in order.
+    #[must_use]
+    pub const fn iter(&self) -> BitIter {
+        BitIter {
+            bits: *self,
+            front: 0,
+            back: self.len,
+        }
+    }
+
+    /// The number of bits which are set.
+    #[must_use]
+    pub fn count_ones(&self) -> usize {
+        let full = &self.bytes[..self.len / 8];
+        let (start, words, end) = bytemuck::pod_align_to::<u8, usize>(full);
+        let mut count: usize = words.iter().map(|word| word.count_ones() as usize).sum();
+        count += start
+            .iter()
+            .chain(end)
+            .map(|byte| byte.count_ones() as usize)
+            .sum::<usize>();
+
+        let rest = self.len % 8;
+        if rest > 0 {
+            let mask = match self.order {
+                BitOrder::LsbFirst => 0xff >> (8 - rest),
+                BitOrder::MsbFirst => 0xff << (8 - rest),
+            };
+            count += (self.bytes[self.len / 8] & mask).count_ones() as usize;
+        }
+        count
+    }
+
+    /// The number of bits which are not set.
+    #[must_use]
+    pub fn count_zeros(&self) -> usize {
+        self.len - self.count_ones()
+    }
+}
+
+impl IntoIterator for BitSlice {
+    type Item = bool;
+    type IntoIter = BitIter;
+
+    fn into_iter(self) -> BitIter {
+        self.iter()
+    }
+}
+
+impl IntoIterator for &BitSlice {
+    type Item = bool;
+    type IntoIter = BitIter;
+
+    fn into_iter(self) -> BitIter {
+        self.iter()
+    }
+}
+
+impl fmt::Debug for BitSlice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("BitSlice[")?;
+        for bit in self {
+            f.write_str(if bit { "1" } else { "0" })?;
+        }
+        f.write_str("]")
+    }
+}
+
+/// An iterator over the bits of a [`BitSlice`].
+#[derive(Clone, Debug)]
+pub struct BitIter {
+    bits: BitSlice,
+    front: usize,
+    back: usize,
+}
+
+impl Iterator for BitIter {
+    type Item = bool;
+
+    fn next(&mut self) -> Option<bool> {
+        if self.front == self.back {
+            return None;
+        }
+        self.front += 1;
+        self.bits.get(self.front - 1)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for BitIter {
+    fn next_back(&mut self) -> Option<bool> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        self.bits.get(self.back)
+    }
+}
+
+impl ExactSizeIterator for BitIter {}
+
+impl FusedIterator for BitIter {}
+
+/// Options for [`include_bits`](crate::include_bits), set by the macro.
+#[doc(hidden)]
+#[derive(Clone, Copy, Debug)]
+pub struct BitOptions {
+    pub order: BitOrder,
+    pub len: Option<usize>,
+    /// The size in bytes of the bit length header, or zero.
+    pub header: usize,
+}
+
+impl BitOptions {
+    pub const DEFAULT: Self = Self {
+        order: BitOrder::LsbFirst,
+        len: None,
+        header: 0,
+    };
+}
+
+/// Include a file as a [`BitSlice`], with eight bits packed into each byte.
+///
+/// This stores booleans eight times more compactly than one per byte (and
+/// `bool` cannot be used with [`include_slice`](crate::include_slice) anyway,
+/// since not every bit pattern is a valid `bool`). The bytes are aligned for
+/// `usize`, so that [`BitSlice::count_ones`] can work a word at a time.
+///
+/// Options may follow the path, in any order:
+/// - `order = lsb` or `order = msb` - whether the first bit of each byte is
+///   its least (the default) or most significant bit
+/// - `len = N` - the exact number of bits: a compiler error will be thrown
+///   unless the file has exactly enough bytes to hold them, and any remaining
+///   bits in the last byte are ignored
+/// - `header = u32` or `header = u64` - the file starts with the exact number
+///   of bits as an integer of that type, in the endianness of the compilation
+///   target, which is checked as for `len` (and against `len`, if both are
+///   given)
+///
+/// Each option value must be a single token, so longer expressions must be
+/// wrapped in parentheses, as in `len = (8 * 12 + 3)`.
+///
+/// This can assign to both `static` and `const` variables, and paths are
+/// interpreted by [`core::include_bytes`].
+///
+/// # Example
+///
+/// ```
+/// # use include_data::{include_bits, BitSlice};
+/// static FLAGS: BitSlice = include_bits!("../tests/test_data/binary_4", order = msb, len = 30);
+///
+/// assert_eq!(FLAGS.len(), 30);
+/// assert_eq!(FLAGS.get(7), Some(false));
+/// assert_eq!(FLAGS.get(15), Some(true));
+/// assert_eq!(FLAGS.count_ones(), 2);
+/// ```
+#[macro_export]
+#[allow(clippy::module_name_repetitions)]
+macro_rules! include_bits {
+    ($file:expr $(, $option:ident = $value:tt)* $(,)?) => {{
+        const OPTIONS: $crate::BitOptions = {
+            #[allow(unused_mut)]
+            let mut options = $crate::BitOptions::DEFAULT;
+            $($crate::__bit_option!(options, $option = $value);)*
+            options
+        };
+
+        const ALIGNED: &$crate::AlignedAs<usize, [u8]> = &$crate::AlignedAs {
+            _align: [],
+            bytes: *::core::include_bytes!($file),
+        };
+        const BITS: $crate::BitSlice = $crate::BitSlice::from_included(&ALIGNED.bytes, OPTIONS);
+
+        BITS
+    }};
+}
+
+/// Set one option of [`include_bits`].
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __bit_option {
+    ($options:ident, order = lsb) => {
+        $options.order = $crate::BitOrder::LsbFirst
+    };
+    ($options:ident, order = msb) => {
+        $options.order = $crate::BitOrder::MsbFirst
+    };
+    ($options:ident, len = $len:tt) => {
+        $options.len = ::core::option::Option::Some($len)
+    };
+    ($options:ident, header = u32) => {
+        $options.header = 4
+    };
+    ($options:ident, header = u64) => {
+        $options.header = 8
+    };
+    ($options:ident, $option:ident = $value:tt) => {
+        ::core::compile_error!(::core::concat!(
+            "Unknown option `",
+            ::core::stringify!($option),
+            " = ",
+            ::core::stringify!($value),
+            "`, expected one of `order = lsb`, `order = msb`, `len = N`, `header = u32` or `header = u64`",
+        ))
+    };
+}
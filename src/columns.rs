@@ -0,0 +1,144 @@
+/// Include several files as the columns of a generated struct of slices,
+/// checking at compile time that every column has the same length.
+///
+/// ```text
+/// include_columns! {
+///     <attrs> <vis> struct Name {
+///         <vis> field_a: T_a = path_a,
+///         <vis> field_b: T_b = path_b,
+///         ...
+///     }
+/// }
+/// ```
+///
+/// defines a `struct Name` with one `&'static [T_i]` field per column, where
+/// column `i` is the contents of the file at `path_i` as if included with
+/// [`include_slice`](crate::include_slice). The included columns are available
+/// from `Name::get()`, which returns a `&'static Name`.
+///
+/// A compiler error will be thrown if any file's size is not divisible by the
+/// size of its column type, or if the columns do not all have the same number
+/// of elements. Paths are interpreted by [`core::include_bytes`].
+///
+/// The generated `struct` also has:
+/// - `const LEN: usize` - the number of elements in every column
+/// - `const fn len(&self) -> usize` and `const fn is_empty(&self) -> bool`
+///
+/// Attributes (including doc comments and derives) and visibilities may be
+/// given for the `struct` and for each field.
+///
+/// # Example
+///
+/// ```
+/// # use include_data::include_columns;
+/// include_columns! {
+///     /// Some columnar data.
+///     #[derive(Debug)]
+///     pub struct Columns {
+///         pub wide: u32 = "../tests/test_data/binary_32",
+///         pub narrow: u8 = "../tests/test_data/binary_8",
+///     }
+/// }
+///
+/// let columns = Columns::get();
+/// assert_eq!(Columns::LEN, 8);
+/// for (wide, narrow) in columns.wide.iter().zip(columns.narrow) {
+///     // ...
+/// }
+/// ```
+///
+/// # Safety
+///
+/// This macro is safe. However, if used on custom types, those types must
+/// implement [`bytemuck::AnyBitPattern`]. Implementing that trait has very
+/// strict safety requirements which must be observed.
+#[macro_export]
+#[allow(clippy::module_name_repetitions)]
+macro_rules! include_columns {
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $name:ident {
+            $(#[$first_attr:meta])*
+            $first_vis:vis $first:ident: $first_ty:ty = $first_file:expr
+            $(
+                ,
+                $(#[$field_attr:meta])*
+                $field_vis:vis $field:ident: $field_ty:ty = $field_file:expr
+            )*
+            $(,)?
+        }
+    ) => {
+        $(#[$attr])*
+        $vis struct $name {
+            $(#[$first_attr])*
+            $first_vis $first: &'static [$first_ty],
+            $(
+                $(#[$field_attr])*
+                $field_vis $field: &'static [$field_ty],
+            )*
+        }
+
+        impl $name {
+            /// The number of elements in every column.
+            pub const LEN: usize = {
+                let len = $crate::__column_len!($first_ty, $first_file);
+                $(
+                    assert!(
+                        $crate::__column_len!($field_ty, $field_file) == len,
+                        ::core::concat!(
+                            "Included column `",
+                            ::core::stringify!($field),
+                            "` does not have the same number of elements as column `",
+                            ::core::stringify!($first),
+                            "`",
+                        ),
+                    );
+                )*
+                len
+            };
+
+            /// The included columns.
+            #[must_use]
+            pub fn get() -> &'static Self {
+                static COLUMNS: $name = $name {
+                    $first: $crate::include_slice!($first_ty, $first_file),
+                    $($field: $crate::include_slice!($field_ty, $field_file),)*
+                };
+
+                &COLUMNS
+            }
+
+            /// The number of elements in every column.
+            #[must_use]
+            pub const fn len(&self) -> usize {
+                Self::LEN
+            }
+
+            /// Whether the columns are empty.
+            #[must_use]
+            pub const fn is_empty(&self) -> bool {
+                Self::LEN == 0
+            }
+        }
+
+        // Force evaluation (and thus length checking) even if `LEN` is never
+        // used.
+        const _: usize = $name::LEN;
+    };
+}
+
+/// The number of elements of a type in an included file, checking that the
+/// file size is divisible by the type size.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __column_len {
+    ($target_ty:ty, $file:expr) => {{
+        const SIZE: usize = ::core::mem::size_of::<$target_ty>();
+        const BYTES: &[u8] = ::core::include_bytes!($file);
+        assert!(
+            BYTES.len() % SIZE == 0,
+            "Included file size is not divisible by target type size",
+        );
+        BYTES.len() / SIZE
+    }};
+}
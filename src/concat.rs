@@ -0,0 +1,150 @@
+/// Include several files as one contiguous `&'static [T]` slice of
+/// [`bytemuck::AnyBitPattern`] types.
+///
+/// `include_slice_concat!(T, [path_a, path_b, ...])` lays the contents of
+/// each file out one after the other, in order, in a single static correctly
+/// aligned for `T`. This has the same guarantees as
+/// [`include_slice`](crate::include_slice): a compiler error will be thrown if
+/// the size of any one file is not divisible by
+/// [`size_of::<T>()`][core::mem::size_of], so elements never straddle two
+/// files. Paths are interpreted by [`core::include_bytes`].
+///
+/// Use [`include_concat_bounds`](crate::include_concat_bounds) with the same
+/// arguments to find where each file's elements start and end.
+///
+/// # Example
+///
+/// ```
+/// # use include_data::{include_concat_bounds, include_slice_concat};
+/// static TABLE: &[u16] = include_slice_concat!(
+///     u16,
+///     ["../tests/test_data/binary_4", "../tests/test_data/binary_8"],
+/// );
+/// const BOUNDS: [usize; 3] = include_concat_bounds!(
+///     u16,
+///     ["../tests/test_data/binary_4", "../tests/test_data/binary_8"],
+/// );
+///
+/// assert_eq!(TABLE.len(), 6);
+/// assert_eq!(BOUNDS, [0, 2, 6]);
+/// let second_file = &TABLE[BOUNDS[1]..BOUNDS[2]];
+/// ```
+///
+/// # Safety
+///
+/// This macro is safe. However, if used on a custom type, that type must
+/// implement [`bytemuck::AnyBitPattern`]. Implementing that trait has very
+/// strict safety requirements which must be observed.
+#[macro_export]
+#[allow(clippy::module_name_repetitions)]
+macro_rules! include_slice_concat {
+    ($target_ty:ty, [$($file:expr),+ $(,)?] $(,)?) => {{
+        const SIZE: usize = ::core::mem::size_of::<$target_ty>();
+        const PARTS: &[&[u8]] = &[$($crate::__concat_part!(SIZE, $file)),+];
+        const LEN: usize = $crate::concat_len(PARTS);
+
+        static ALIGNED: &$crate::AlignedAs<$target_ty, [u8]> = &$crate::AlignedAs {
+            _align: [],
+            bytes: $crate::concat_bytes::<LEN>(PARTS),
+        };
+
+        let out_slice: &'static [$target_ty] = unsafe {
+            ::core::slice::from_raw_parts(ALIGNED.bytes.as_ptr().cast(), LEN / SIZE)
+        };
+
+        out_slice
+    }};
+}
+
+/// The boundaries between files in a slice included with
+/// [`include_slice_concat`](crate::include_slice_concat), as a `const` array.
+///
+/// `include_concat_bounds!(T, [path_a, path_b, ...])` takes the same arguments
+/// as `include_slice_concat` and evaluates to a `[usize; N + 1]` array for `N`
+/// files. Element `i` is the index in the concatenated slice of the first
+/// element from file `i`, and the last element is the length of the slice, so
+/// the elements from file `i` are `slice[bounds[i]..bounds[i + 1]]`.
+///
+/// Only the sizes of the files are used: their data is not included in the
+/// executable again.
+#[macro_export]
+macro_rules! include_concat_bounds {
+    ($target_ty:ty, [$($file:expr),+ $(,)?] $(,)?) => {{
+        const SIZE: usize = ::core::mem::size_of::<$target_ty>();
+        const PARTS: &[&[u8]] = &[$($crate::__concat_part!(SIZE, $file)),+];
+        const BOUNDS: [usize; [$(::core::stringify!($file)),+].len() + 1] =
+            $crate::concat_bounds(PARTS, SIZE);
+
+        BOUNDS
+    }};
+}
+
+/// Include one part of a concatenated slice, checking its size.
+#[doc(hidden)]
+#[macro_export]
+#[allow(clippy::module_name_repetitions)]
+macro_rules! __concat_part {
+    ($size:expr, $file:expr) => {{
+        const PART: &[u8] = ::core::include_bytes!($file);
+        assert!(
+            PART.len() % $size == 0,
+            ::core::concat!(
+                "Size of included file ",
+                ::core::stringify!($file),
+                " is not divisible by target type size",
+            ),
+        );
+        PART
+    }};
+}
+
+/// The total length of some byte slices.
+#[doc(hidden)]
+#[must_use]
+#[allow(clippy::module_name_repetitions)]
+pub const fn concat_len(parts: &[&[u8]]) -> usize {
+    let mut len = 0;
+    let mut i = 0;
+    while i < parts.len() {
+        len += parts[i].len();
+        i += 1;
+    }
+    len
+}
+
+/// Concatenate byte slices with a total length of `N` into an array.
+#[doc(hidden)]
+#[must_use]
+#[allow(clippy::module_name_repetitions)]
+pub const fn concat_bytes<const N: usize>(parts: &[&[u8]]) -> [u8; N] {
+    let mut out = [0; N];
+    let mut offset = 0;
+    let mut i = 0;
+    while i < parts.len() {
+        let mut j = 0;
+        while j < parts[i].len() {
+            out[offset + j] = parts[i][j];
+            j += 1;
+        }
+        offset += parts[i].len();
+        i += 1;
+    }
+    assert!(offset == N, "Concatenated length does not match");
+    out
+}
+
+/// The element indices at which each of `N - 1` parts start, followed by the
+/// total number of elements.
+#[doc(hidden)]
+#[must_use]
+#[allow(clippy::module_name_repetitions)]
+pub const fn concat_bounds<const N: usize>(parts: &[&[u8]], size: usize) -> [usize; N] {
+    assert!(parts.len() + 1 == N, "Wrong number of concatenated parts");
+    let mut out = [0; N];
+    let mut i = 0;
+    while i < parts.len() {
+        out[i + 1] = out[i] + parts[i].len() / size;
+        i += 1;
+    }
+    out
+}
@@ -0,0 +1,48 @@
+/// Panic with a message containing `index`, between `before` and `after`.
+///
+/// Used in `const` contexts to name the element at fault in a compiler error.
+pub(crate) const fn index_panic(before: &[u8], index: usize, after: &[u8]) -> ! {
+    let mut message = [0; 128];
+    let mut len = 0;
+
+    let mut i = 0;
+    while i < before.len() {
+        message[len] = before[i];
+        len += 1;
+        i += 1;
+    }
+
+    let mut digits = [0; 20];
+    let mut digit_count = 0;
+    let mut rest = index;
+    loop {
+        // Cannot truncate, since the digit is at most 9.
+        #[allow(clippy::cast_possible_truncation)]
+        let digit = (rest % 10) as u8;
+        digits[digit_count] = b'0' + digit;
+        digit_count += 1;
+        rest /= 10;
+        if rest == 0 {
+            break;
+        }
+    }
+    while digit_count > 0 {
+        digit_count -= 1;
+        message[len] = digits[digit_count];
+        len += 1;
+    }
+
+    let mut i = 0;
+    while i < after.len() {
+        message[len] = after[i];
+        len += 1;
+        i += 1;
+    }
+
+    // Safety: the message is made of ASCII from the string literals above and
+    // digits.
+    let message = unsafe {
+        core::str::from_utf8_unchecked(core::slice::from_raw_parts(message.as_ptr(), len))
+    };
+    panic!("{}", message);
+}
@@ -0,0 +1,48 @@
+/// Byte order of multi-byte values.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Endian {
+    /// Least significant byte first.
+    Little,
+    /// Most significant byte first.
+    Big,
+}
+
+impl Endian {
+    /// The endianness of the compilation target.
+    #[cfg(target_endian = "little")]
+    pub const NATIVE: Self = Self::Little;
+    /// The endianness of the compilation target.
+    #[cfg(target_endian = "big")]
+    pub const NATIVE: Self = Self::Big;
+
+    /// Whether this is the endianness of the compilation target.
+    #[must_use]
+    pub const fn is_native(self) -> bool {
+        self as u8 == Self::NATIVE as u8
+    }
+}
+
+/// The `N` bytes of `bytes`, which holds values of `size` bytes each in the
+/// given byte order, with every value converted to native byte order.
+#[doc(hidden)]
+#[must_use]
+pub const fn to_native_bytes<const N: usize>(bytes: &[u8], size: usize, endian: Endian) -> [u8; N] {
+    assert!(
+        bytes.len() % size == 0,
+        "Included file size is not divisible by target type size",
+    );
+    assert!(bytes.len() == N, "Wrong length of byte-swapped data");
+
+    let mut out = [0; N];
+    let mut i = 0;
+    while i < N {
+        let offset = i % size;
+        out[i] = if endian.is_native() {
+            bytes[i]
+        } else {
+            bytes[i - offset + size - 1 - offset]
+        };
+        i += 1;
+    }
+    out
+}
@@ -0,0 +1,218 @@
+/// Whether a file exists, as a `const bool`.
+///
+/// The path is interpreted relative to the directory containing the crate's
+/// `Cargo.toml`, as for the fallback include macros
+/// ([`include_slice_or`](crate::include_slice_or),
+/// [`include_data_or`](crate::include_data_or) and
+/// [`include_data_or_default`](crate::include_data_or_default)). To tell
+/// whether one of those macros found its file, prefer passing it a `found`
+/// argument, which cannot disagree with the included value.
+///
+/// Requires the `fallback` feature.
+///
+/// # Example
+///
+/// ```
+/// # use include_data::file_exists;
+/// const WAS_FOUND: bool = file_exists!("tests/test_data/binary_4");
+/// const NOT_FOUND: bool = file_exists!("tests/test_data/non-existent");
+///
+/// assert!(WAS_FOUND);
+/// assert!(!NOT_FOUND);
+/// ```
+#[macro_export]
+macro_rules! file_exists {
+    ($file:literal $(,)?) => {
+        $crate::__if_file_exists_impl! { $file, { true }, { false } }
+    };
+}
+
+/// As [`include_slice`](crate::include_slice), but evaluating to a default
+/// slice if the file does not exist.
+///
+/// `include_slice_or!(T, path, default)` evaluates to a `&'static [T]`. If the
+/// file exists, it is included with exactly the same checks as
+/// `include_slice`: a compiler error will be thrown if its size is not
+/// divisible by [`size_of::<T>()`][core::mem::size_of]. Otherwise, it
+/// evaluates to `default`, which must be a `&'static [T]`.
+///
+/// Requires the `fallback` feature.
+///
+/// # Paths
+///
+/// Unlike [`include_slice`](crate::include_slice), the path must be a string
+/// literal and is interpreted relative to the directory containing the crate's
+/// `Cargo.toml`, *not* relative to the file containing the macro call.
+///
+/// Changes to a file which exists will trigger a rebuild. Adding a file which
+/// did not exist will only do so with the `nightly` feature, which needs a
+/// nightly compiler to track missing files. On stable Rust, emit
+/// `cargo:rerun-if-changed` for the path from a build script.
+///
+/// # Whether the file was found
+///
+/// With a trailing `found` argument, `include_slice_or!(T, path, default,
+/// found)` evaluates to a `(&'static [T], bool)` pair of the slice and whether
+/// the file was found. This can be assigned to a `const`, so that the flag can
+/// be used in `const` contexts, and the slice then assigned to a `static`.
+///
+/// # Example
+///
+/// ```
+/// # use include_data::include_slice_or;
+/// static GAINS: &[f32] = include_slice_or!(f32, "tests/test_data/non-existent", &[1.0, 1.0]);
+///
+/// const CALIBRATION: (&[f32], bool) =
+///     include_slice_or!(f32, "tests/test_data/non-existent", &[], found);
+/// static OFFSETS: &[f32] = CALIBRATION.0;
+/// const CALIBRATED: bool = CALIBRATION.1;
+///
+/// assert_eq!(GAINS, [1.0, 1.0]);
+/// assert!(OFFSETS.is_empty());
+/// assert!(!CALIBRATED);
+/// ```
+///
+/// # Safety
+///
+/// This macro is safe. However, if used on a custom type, that type must
+/// implement [`bytemuck::AnyBitPattern`]. Implementing that trait has very
+/// strict safety requirements which must be observed.
+#[macro_export]
+macro_rules! include_slice_or {
+    ($target_ty:ty, $file:literal, $default:expr, found $(,)?) => {
+        $crate::__if_file_exists_impl! {
+            $file,
+            {(
+                $crate::__include_slice_const!($target_ty, $crate::__resolve_path!(manifest, $file)),
+                true,
+            )},
+            {{
+                let out_slice: &'static [$target_ty] = $default;
+                (out_slice, false)
+            }},
+        }
+    };
+    ($target_ty:ty, $file:literal, $default:expr $(,)?) => {
+        $crate::__if_file_exists_impl! {
+            $file,
+            { $crate::include_slice!($target_ty, $file, from = manifest) },
+            {{
+                let out_slice: &'static [$target_ty] = $default;
+                out_slice
+            }},
+        }
+    };
+}
+
+/// As [`include_data`](crate::include_data), but evaluating to a default
+/// value if the file does not exist.
+///
+/// `include_data_or!(path, default)` evaluates to the contents of the file at
+/// `path` if it exists, with exactly the same checks as `include_data`.
+/// Otherwise, it evaluates to `default`. Can assign to both `static` and
+/// `const` variables.
+///
+/// Requires the `fallback` feature.
+///
+/// # Paths
+///
+/// As for [`include_slice_or`](crate::include_slice_or#paths), the path must
+/// be a string literal and is interpreted relative to the directory containing
+/// the crate's `Cargo.toml`.
+///
+/// # Whether the file was found
+///
+/// As for [`include_slice_or`](crate::include_slice_or#whether-the-file-was-found),
+/// `include_data_or!(path, default, found)` evaluates to a pair of the value
+/// and whether the file was found.
+///
+/// # Example
+///
+/// ```
+/// # use include_data::include_data_or;
+/// const GAIN: f32 = include_data_or!("tests/test_data/non-existent", 1.0);
+/// const FOUND: [u8; 4] = include_data_or!("tests/test_data/binary_4", [0; 4]);
+/// const OFFSET: (i32, bool) = include_data_or!("tests/test_data/non-existent", 0, found);
+///
+/// assert_eq!(GAIN, 1.0);
+/// assert_eq!(FOUND, [0, 1, 2, 3]);
+/// assert_eq!(OFFSET, (0, false));
+/// ```
+///
+/// # Safety
+///
+/// This macro is safe. However, if used on a custom type, that type must
+/// implement [`bytemuck::AnyBitPattern`]. Implementing that trait has very
+/// strict safety requirements which must be observed.
+#[macro_export]
+macro_rules! include_data_or {
+    ($file:literal, $default:expr, found $(,)?) => {
+        $crate::__if_file_exists_impl! {
+            $file,
+            { ($crate::include_data!($file, from = manifest), true) },
+            { ($default, false) },
+        }
+    };
+    ($file:literal, $default:expr $(,)?) => {
+        $crate::__if_file_exists_impl! {
+            $file,
+            { $crate::include_data!($file, from = manifest) },
+            { $default },
+        }
+    };
+}
+
+/// As [`include_data_or`](crate::include_data_or), but evaluating to all zeroes (as
+/// [`bytemuck::Zeroable::zeroed`]) if the file does not exist.
+///
+/// `include_data_or_default!(T, path)` evaluates to a `T`. Unlike
+/// `Zeroable::zeroed`, this can assign to both `static` and `const` variables.
+/// As for [`include_data_or`](crate::include_data_or), a trailing `found`
+/// argument makes it evaluate to a pair of the value and whether the file was
+/// found.
+///
+/// Requires the `fallback` feature.
+///
+/// # Example
+///
+/// ```
+/// # use include_data::include_data_or_default;
+/// const OFFSETS: [i16; 2] = include_data_or_default!([i16; 2], "tests/test_data/non-existent");
+/// const SCALE: (f32, bool) = include_data_or_default!(f32, "tests/test_data/non-existent", found);
+///
+/// assert_eq!(OFFSETS, [0, 0]);
+/// assert_eq!(SCALE, (0.0, false));
+/// ```
+///
+/// # Safety
+///
+/// This macro is safe. However, if used on a custom type, that type must
+/// implement [`bytemuck::AnyBitPattern`]. Implementing that trait has very
+/// strict safety requirements which must be observed.
+#[macro_export]
+macro_rules! include_data_or_default {
+    ($target_ty:ty, $file:literal, found $(,)?) => {
+        $crate::__if_file_exists_impl! {
+            $file,
+            {{
+                let out: $target_ty = $crate::include_data!($file, from = manifest);
+                (out, true)
+            }},
+            {{
+                const fn typecheck<T: $crate::bytemuck::AnyBitPattern>(src: T) -> T {
+                    src
+                }
+
+                // Safety: `AnyBitPattern` types are `Zeroable`, so all zeroes
+                // is a valid bit pattern.
+                let out = typecheck::<$target_ty>(unsafe {
+                    ::core::mem::transmute([0_u8; ::core::mem::size_of::<$target_ty>()])
+                });
+                (out, false)
+            }},
+        }
+    };
+    ($target_ty:ty, $file:literal $(,)?) => {
+        $crate::include_data_or_default!($target_ty, $file, found).0
+    };
+}
@@ -0,0 +1,174 @@
+/// A compile-time fingerprint of a type's memory layout.
+///
+/// The fingerprint is a hash of the type's size, alignment and (for structs)
+/// the offset and fingerprint of every field. Since Rust has no stable ABI,
+/// the layout of a type can change between compiler versions, compiler
+/// profiles and targets. Comparing fingerprints detects such changes, which
+/// would otherwise silently make uses of [`include_unsafe`](crate::include_unsafe) unsound.
+///
+/// This trait is implemented for primitive types and arrays, and can be
+/// derived for structs with `#[derive(LayoutFingerprint)]` (requires the
+/// `derive` feature). Implementations can also be written by hand using
+/// [`FingerprintHasher`].
+///
+/// Fingerprints are only intended to detect accidental layout changes: they
+/// are not cryptographically secure and should not be relied upon to detect
+/// malicious changes.
+///
+/// # Example
+///
+/// ```
+/// # use include_data::LayoutFingerprint;
+/// assert_ne!(u32::FINGERPRINT, f32::FINGERPRINT);
+/// assert_ne!(<[u8; 4]>::FINGERPRINT, <[u8; 3]>::FINGERPRINT);
+/// ```
+#[allow(clippy::module_name_repetitions)]
+pub trait LayoutFingerprint {
+    /// The layout fingerprint of this type.
+    const FINGERPRINT: u64;
+}
+
+/// A `const` hasher for computing [`LayoutFingerprint`]s.
+///
+/// This is a 64-bit FNV-1a hasher. Multi-byte values are always hashed in
+/// little-endian order, so the hash of the same values is identical on every
+/// platform.
+///
+/// # Example
+///
+/// ```
+/// # use include_data::{FingerprintHasher, LayoutFingerprint};
+/// #[repr(transparent)]
+/// struct Meters(f64);
+///
+/// impl LayoutFingerprint for Meters {
+///     const FINGERPRINT: u64 = FingerprintHasher::new()
+///         .write_str("Meters")
+///         .write_u64(f64::FINGERPRINT)
+///         .finish();
+/// }
+/// ```
+#[derive(Clone, Copy, Debug)]
+#[allow(clippy::module_name_repetitions)]
+pub struct FingerprintHasher {
+    state: u64,
+}
+
+impl FingerprintHasher {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    /// Create a new hasher.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            state: Self::OFFSET_BASIS,
+        }
+    }
+
+    /// Hash some bytes.
+    #[must_use]
+    pub const fn write(mut self, bytes: &[u8]) -> Self {
+        let mut i = 0;
+        while i < bytes.len() {
+            self.state ^= bytes[i] as u64;
+            self.state = self.state.wrapping_mul(Self::PRIME);
+            i += 1;
+        }
+        self
+    }
+
+    /// Hash a string, including its length.
+    #[must_use]
+    pub const fn write_str(self, s: &str) -> Self {
+        self.write_usize(s.len()).write(s.as_bytes())
+    }
+
+    /// Hash a `u64`.
+    #[must_use]
+    pub const fn write_u64(self, value: u64) -> Self {
+        self.write(&value.to_le_bytes())
+    }
+
+    /// Hash a `usize`, as a `u64` so that the result is platform-independent.
+    #[must_use]
+    pub const fn write_usize(self, value: usize) -> Self {
+        self.write_u64(value as u64)
+    }
+
+    /// The resulting hash.
+    #[must_use]
+    pub const fn finish(self) -> u64 {
+        self.state
+    }
+}
+
+impl Default for FingerprintHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+macro_rules! impl_primitive_fingerprint {
+    ($($ty:ty),* $(,)?) => {$(
+        impl LayoutFingerprint for $ty {
+            const FINGERPRINT: u64 = FingerprintHasher::new()
+                .write_str(stringify!($ty))
+                .write_usize(::core::mem::size_of::<$ty>())
+                .write_usize(::core::mem::align_of::<$ty>())
+                .finish();
+        }
+    )*};
+}
+
+impl_primitive_fingerprint!(
+    (),
+    bool,
+    char,
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    usize,
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    isize,
+    f32,
+    f64,
+);
+
+impl<T: LayoutFingerprint, const N: usize> LayoutFingerprint for [T; N] {
+    const FINGERPRINT: u64 = FingerprintHasher::new()
+        .write_str("[T; N]")
+        .write_u64(T::FINGERPRINT)
+        .write_usize(N)
+        .write_usize(::core::mem::size_of::<Self>())
+        .write_usize(::core::mem::align_of::<Self>())
+        .finish();
+}
+
+/// Byte length of the header read by
+/// [`include_unsafe!(path, fingerprint_header)`](crate::include_unsafe).
+#[doc(hidden)]
+pub const FINGERPRINT_HEADER_LEN: usize = 8;
+
+/// The byte offset of a field within a type, usable in `const` contexts.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __offset_of {
+    ($ty:ty, $field:tt) => {{
+        let uninit = ::core::mem::MaybeUninit::<$ty>::uninit();
+        let base = uninit.as_ptr();
+        // Safety: `addr_of!` never creates a reference to (or reads) the
+        // uninitialized field, and both pointers are within the same
+        // allocation.
+        unsafe {
+            let field = ::core::ptr::addr_of!((*base).$field);
+            field.cast::<u8>().offset_from(base.cast::<u8>()) as usize
+        }
+    }};
+}
@@ -0,0 +1,379 @@
+use core::fmt;
+
+use crate::const_panic::index_panic;
+use crate::sorted::{ConstOrd, OrdKind, OrdLayout};
+
+/// Signed fixed-point number types, which can be included from decimal text
+/// with [`include_decimals`](crate::include_decimals).
+///
+/// A value is stored as a two's complement integer of at most 32 bits, equal
+/// to the value multiplied by `2^FRAC_BITS`. Implemented by [`Q7`], [`Q15`]
+/// and [`Q31`].
+#[allow(clippy::module_name_repetitions)]
+pub trait FixedPoint: bytemuck::Pod {
+    /// The number of fractional bits.
+    const FRAC_BITS: u32;
+}
+
+macro_rules! fixed_point {
+    ($(#[$attr:meta])* $name:ident($bits_ty:ty)) => {
+        $(#[$attr])*
+        #[repr(transparent)]
+        #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub struct $name($bits_ty);
+
+        // Safety: a transparent wrapper of a primitive integer, so every bit
+        // pattern is valid and there is no padding.
+        unsafe impl bytemuck::Zeroable for $name {}
+        unsafe impl bytemuck::Pod for $name {}
+
+        impl FixedPoint for $name {
+            const FRAC_BITS: u32 = <$bits_ty>::BITS - 1;
+        }
+
+        // Safety: a single signed integer, which has the same order.
+        unsafe impl ConstOrd for $name {
+            const LAYOUT: OrdLayout = OrdLayout {
+                kind: OrdKind::Signed,
+                size: core::mem::size_of::<$name>(),
+                count: 1,
+            };
+        }
+
+        impl $name {
+            /// Zero.
+            pub const ZERO: Self = Self(0);
+            /// The smallest value, `-1.0`.
+            pub const MIN: Self = Self(<$bits_ty>::MIN);
+            /// The largest value, `1.0 - 2^-FRAC_BITS`.
+            pub const MAX: Self = Self(<$bits_ty>::MAX);
+
+            /// `2^FRAC_BITS`, the value of one in the underlying bits.
+            // Cannot lose precision, since it is a power of two.
+            #[allow(clippy::cast_precision_loss)]
+            const SCALE: f64 = (1_u64 << <Self as FixedPoint>::FRAC_BITS) as f64;
+
+            /// The value with the given underlying bits, which are the value
+            /// multiplied by `2^FRAC_BITS`.
+            #[must_use]
+            pub const fn from_bits(bits: $bits_ty) -> Self {
+                Self(bits)
+            }
+
+            /// The underlying bits, which are the value multiplied by
+            /// `2^FRAC_BITS`.
+            #[must_use]
+            pub const fn to_bits(self) -> $bits_ty {
+                self.0
+            }
+
+            /// The nearest value to `value`, with ties rounded away from zero,
+            /// saturating at [`Self::MIN`] and [`Self::MAX`]. NaN is converted
+            /// to zero.
+            #[must_use]
+            pub fn from_f64(value: f64) -> Self {
+                let scaled = value * Self::SCALE;
+                // Float to integer casts truncate towards zero, saturate, and
+                // convert NaN to zero.
+                #[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+                let (truncated, fraction) = {
+                    let truncated = scaled as i64;
+                    (truncated, scaled - truncated as f64)
+                };
+                let rounded = if fraction >= 0.5 {
+                    truncated.saturating_add(1)
+                } else if fraction <= -0.5 {
+                    truncated.saturating_sub(1)
+                } else {
+                    truncated
+                };
+                let clamped =
+                    rounded.clamp(i64::from(<$bits_ty>::MIN), i64::from(<$bits_ty>::MAX));
+                // Cannot truncate, since it is clamped to the range of the type.
+                #[allow(clippy::cast_possible_truncation)]
+                let bits = clamped as $bits_ty;
+                Self(bits)
+            }
+
+            /// The value as an `f32`, rounded if necessary.
+            #[must_use]
+            pub fn to_f32(self) -> f32 {
+                // Rounding is the documented behaviour.
+                #[allow(clippy::cast_possible_truncation)]
+                let value = self.to_f64() as f32;
+                value
+            }
+
+            /// The value as an `f64`, which is exact.
+            #[must_use]
+            pub fn to_f64(self) -> f64 {
+                f64::from(self.0) / Self::SCALE
+            }
+        }
+
+        impl From<$name> for f64 {
+            fn from(value: $name) -> f64 {
+                value.to_f64()
+            }
+        }
+
+        impl fmt::Debug for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Debug::fmt(&self.to_f64(), f)
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(&self.to_f64(), f)
+            }
+        }
+    };
+}
+
+fixed_point!(
+    /// A signed 8-bit fixed-point number with 7 fractional bits, in the range
+    /// `-1.0..1.0`.
+    Q7(i8)
+);
+
+fixed_point!(
+    /// A signed 16-bit fixed-point number with 15 fractional bits, in the
+    /// range `-1.0..1.0`, as commonly used for DSP coefficients.
+    Q15(i16)
+);
+
+fixed_point!(
+    /// A signed 32-bit fixed-point number with 31 fractional bits, in the
+    /// range `-1.0..1.0`, as commonly used for DSP coefficients.
+    Q31(i32)
+);
+
+/// The most fractional digits of a decimal number which are taken into
+/// account. Any beyond this are far smaller than the precision of any
+/// supported type.
+const MAX_FRAC_DIGITS: u32 = 19;
+
+/// The number of decimal numbers in `text`.
+#[doc(hidden)]
+#[must_use]
+pub const fn decimal_count(text: &str) -> usize {
+    let bytes = text.as_bytes();
+    let mut count = 0;
+    let mut pos = 0;
+    while let Some((_, next)) = next_decimal(bytes, pos) {
+        count += 1;
+        pos = next;
+    }
+    count
+}
+
+/// The native-endian bytes of the decimal numbers in `text`, each converted to
+/// a signed fixed-point number of `size` bytes with `frac_bits` fractional
+/// bits and rounded to nearest, with ties away from zero.
+#[doc(hidden)]
+#[must_use]
+#[allow(clippy::module_name_repetitions)]
+pub const fn decimal_fixed<const N: usize>(text: &str, size: usize, frac_bits: u32) -> [u8; N] {
+    assert!(
+        size <= 4 && (frac_bits as usize) < 8 * size,
+        "Fixed-point types must be at most 32 bits, including a sign bit",
+    );
+
+    let bytes = text.as_bytes();
+    let mut out = [0; N];
+    let mut index = 0;
+    let mut pos = 0;
+    while let Some((start, next)) = next_decimal(bytes, pos) {
+        let Some((negative, magnitude)) = parse_fixed(bytes, start, next, frac_bits) else {
+            index_panic(b"Included text contains an invalid number at number ", index, b"");
+        };
+
+        let limit = 1 << (8 * size - 1);
+        if magnitude > limit || (magnitude == limit && !negative) {
+            index_panic(
+                b"Included number ",
+                index,
+                b" is out of range for the fixed-point type",
+            );
+        }
+        // Cannot wrap, since the magnitude is at most `2^31`.
+        #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+        let bits = if negative {
+            -(magnitude as i64)
+        } else {
+            magnitude as i64
+        };
+
+        let mut i = 0;
+        while i < size {
+            let shift = if cfg!(target_endian = "little") {
+                8 * i
+            } else {
+                8 * (size - 1 - i)
+            };
+            // Cannot truncate, since the value is masked to one byte.
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            let byte = (bits >> shift & 0xff) as u8;
+            out[index * size + i] = byte;
+            i += 1;
+        }
+
+        index += 1;
+        pos = next;
+    }
+    assert!(index * size == N, "Wrong length of fixed-point data");
+    out
+}
+
+/// The start and end of the next number at or after `pos`, skipping commas,
+/// ASCII whitespace and comments from `#` to the end of the line.
+const fn next_decimal(bytes: &[u8], mut pos: usize) -> Option<(usize, usize)> {
+    while pos < bytes.len() {
+        match bytes[pos] {
+            b'#' => {
+                while pos < bytes.len() && bytes[pos] != b'\n' {
+                    pos += 1;
+                }
+            }
+            byte if byte == b',' || byte.is_ascii_whitespace() => pos += 1,
+            _ => {
+                let start = pos;
+                while pos < bytes.len()
+                    && !matches!(bytes[pos], b',' | b'#')
+                    && !bytes[pos].is_ascii_whitespace()
+                {
+                    pos += 1;
+                }
+                return Some((start, pos));
+            }
+        }
+    }
+    None
+}
+
+/// Whether the decimal number `bytes[start..end]` is negative, and its
+/// magnitude multiplied by `2^frac_bits` and rounded, or `None` if it is not a
+/// valid decimal number. Magnitudes too large for any fixed-point type are
+/// clamped.
+const fn parse_fixed(
+    bytes: &[u8],
+    mut start: usize,
+    end: usize,
+    frac_bits: u32,
+) -> Option<(bool, u128)> {
+    let negative = bytes[start] == b'-';
+    if negative || bytes[start] == b'+' {
+        start += 1;
+    }
+
+    // The magnitude is `digits / 10^frac_digits`.
+    let mut digits: u128 = 0;
+    let mut frac_digits = 0;
+    let mut seen_digit = false;
+    let mut seen_point = false;
+    let mut pos = start;
+    while pos < end {
+        let byte = bytes[pos];
+        if byte == b'.' && !seen_point {
+            seen_point = true;
+        } else if byte.is_ascii_digit() {
+            seen_digit = true;
+            let digit = (byte - b'0') as u128;
+            if !seen_point {
+                digits = digits * 10 + digit;
+                // Any integer part this large is out of range, and clamping
+                // it keeps the arithmetic below from overflowing.
+                if digits > 1 << 32 {
+                    digits = 1 << 32;
+                }
+            } else if frac_digits < MAX_FRAC_DIGITS {
+                digits = digits * 10 + digit;
+                frac_digits += 1;
+            }
+        } else {
+            return None;
+        }
+        pos += 1;
+    }
+    if !seen_digit {
+        return None;
+    }
+
+    let divisor = 10_u128.pow(frac_digits);
+    Some((negative, ((digits << frac_bits) + divisor / 2) / divisor))
+}
+
+/// Include a text file of decimal numbers as a slice of fixed-point numbers,
+/// converted at compile time.
+///
+/// `include_decimals!(T, path)` parses the file at `path` as decimal numbers
+/// such as `0.25`, `-.5` or `+1`, separated by commas or ASCII whitespace. A
+/// `#` starts a comment which lasts until the end of the line. Each number is
+/// rounded to the nearest value of `T`, which must implement [`FixedPoint`],
+/// with ties rounded away from zero. This makes it possible to keep tables of
+/// coefficients, for example from a CSV file, in a readable format.
+///
+/// A compiler error will be thrown naming the first number which is not valid
+/// or which is out of range for `T`: for example, `1.0` is out of range for
+/// [`Q15`], whose largest value is `1.0 - 2^-15`. This can assign to both
+/// `static` and `const` variables, and paths are interpreted by
+/// [`core::include_str`].
+///
+/// # Example
+///
+/// ```
+/// # use include_data::{include_decimals, Q15};
+/// static COEFFICIENTS: &[Q15] = include_decimals!(Q15, "../tests/test_data/q15_decimal");
+///
+/// assert_eq!(COEFFICIENTS[0], Q15::from_bits(0x4000));
+/// assert_eq!(COEFFICIENTS[1].to_f32(), -0.25);
+/// ```
+#[macro_export]
+macro_rules! include_decimals {
+    ($target_ty:ty, $file:expr $(,)?) => {{
+        const SIZE: usize = ::core::mem::size_of::<$target_ty>();
+        const TEXT: &str = ::core::include_str!($file);
+        const COUNT: usize = $crate::decimal_count(TEXT);
+
+        const ALIGNED: &$crate::AlignedAs<$target_ty, [u8]> = &$crate::AlignedAs {
+            _align: [],
+            bytes: $crate::decimal_fixed::<{ COUNT * SIZE }>(
+                TEXT,
+                SIZE,
+                <$target_ty as $crate::FixedPoint>::FRAC_BITS,
+            ),
+        };
+        const OUT_SLICE: &[$target_ty] =
+            unsafe { ::core::slice::from_raw_parts(ALIGNED.bytes.as_ptr().cast(), COUNT) };
+
+        OUT_SLICE
+    }};
+}
+
+/// Alias of [`include_slice(Q7, path)`](crate::include_slice). Returns a
+/// `&'static [Q7]`.
+#[macro_export]
+macro_rules! include_q7s {
+    ($($args:tt)*) => {
+        $crate::include_slice!($crate::Q7, $($args)*)
+    };
+}
+
+/// Alias of [`include_slice(Q15, path)`](crate::include_slice). Returns a
+/// `&'static [Q15]`.
+#[macro_export]
+macro_rules! include_q15s {
+    ($($args:tt)*) => {
+        $crate::include_slice!($crate::Q15, $($args)*)
+    };
+}
+
+/// Alias of [`include_slice(Q31, path)`](crate::include_slice). Returns a
+/// `&'static [Q31]`.
+#[macro_export]
+macro_rules! include_q31s {
+    ($($args:tt)*) => {
+        $crate::include_slice!($crate::Q31, $($args)*)
+    };
+}
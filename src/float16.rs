@@ -0,0 +1,79 @@
+/// Alias of [`include_slice(half::f16, path)`](crate::include_slice). Returns a
+/// `&'static [half::f16]`.
+///
+/// As with the other aliases, values are read in the endianness of the
+/// compilation target. See [`include_f16s_le`](crate::include_f16s_le) and
+/// [`include_f16s_be`](crate::include_f16s_be) for files with a fixed byte
+/// order.
+#[macro_export]
+macro_rules! include_f16s {
+    ($($args:tt)*) => {
+        $crate::include_slice!($crate::half::f16, $($args)*)
+    };
+}
+
+/// Alias of [`include_slice(half::bf16, path)`](crate::include_slice). Returns
+/// a `&'static [half::bf16]`.
+///
+/// As with the other aliases, values are read in the endianness of the
+/// compilation target. See [`include_bf16s_le`](crate::include_bf16s_le) and
+/// [`include_bf16s_be`](crate::include_bf16s_be) for files with a fixed byte
+/// order.
+#[macro_export]
+macro_rules! include_bf16s {
+    ($($args:tt)*) => {
+        $crate::include_slice!($crate::half::bf16, $($args)*)
+    };
+}
+
+/// As [`include_f16s`](crate::include_f16s), but for a file of little-endian
+/// values, which are byte-swapped at compile time on big-endian targets.
+/// Returns a `&'static [half::f16]`.
+#[macro_export]
+macro_rules! include_f16s_le {
+    ($($args:tt)*) => {
+        $crate::__include_slice_endian!($crate::half::f16, $crate::Endian::Little, $($args)*)
+    };
+}
+
+/// As [`include_f16s`](crate::include_f16s), but for a file of big-endian
+/// values, which are byte-swapped at compile time on little-endian targets.
+/// Returns a `&'static [half::f16]`.
+///
+/// # Example
+///
+/// ```
+/// # use include_data::include_f16s_be;
+/// use half::f16;
+///
+/// static WEIGHTS: &[f16] = include_f16s_be!("../tests/test_data/f16_be");
+///
+/// assert_eq!(WEIGHTS[0], f16::ONE);
+/// assert_eq!(WEIGHTS[1].to_f32(), -2.0);
+/// ```
+#[macro_export]
+macro_rules! include_f16s_be {
+    ($($args:tt)*) => {
+        $crate::__include_slice_endian!($crate::half::f16, $crate::Endian::Big, $($args)*)
+    };
+}
+
+/// As [`include_bf16s`](crate::include_bf16s), but for a file of
+/// little-endian values, which are byte-swapped at compile time on big-endian
+/// targets. Returns a `&'static [half::bf16]`.
+#[macro_export]
+macro_rules! include_bf16s_le {
+    ($($args:tt)*) => {
+        $crate::__include_slice_endian!($crate::half::bf16, $crate::Endian::Little, $($args)*)
+    };
+}
+
+/// As [`include_bf16s`](crate::include_bf16s), but for a file of big-endian
+/// values, which are byte-swapped at compile time on little-endian targets.
+/// Returns a `&'static [half::bf16]`.
+#[macro_export]
+macro_rules! include_bf16s_be {
+    ($($args:tt)*) => {
+        $crate::__include_slice_endian!($crate::half::bf16, $crate::Endian::Big, $($args)*)
+    };
+}
@@ -0,0 +1,53 @@
+/// As [`AlignedAs`](crate::AlignedAs), but for types which implement
+/// `zerocopy::FromBytes` rather than `bytemuck::AnyBitPattern`.
+#[doc(hidden)]
+#[repr(C)]
+pub struct ZerocopyAligned<T: zerocopy::FromBytes + zerocopy::Immutable, B: ?Sized> {
+    pub _align: [T; 0],
+    pub bytes: B,
+}
+
+/// [`include_data`](crate::include_data) with `via = zerocopy`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __include_data_zerocopy {
+    ($file:expr) => {{
+        const fn typecheck<T: $crate::zerocopy::FromBytes>(src: T) -> T {
+            src
+        }
+
+        // Safety: transmuting into a `FromBytes` type is always sound (as all
+        // bit patterns are valid). Alignment of the output type is guaranteed
+        // by `transmute`.
+        typecheck(unsafe { ::core::mem::transmute(*::core::include_bytes!($file)) })
+    }};
+}
+
+/// [`include_slice`](crate::include_slice) with `via = zerocopy`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __include_slice_zerocopy {
+    ($target_ty:ty, $file:expr) => {{
+        const SIZE: usize = ::core::mem::size_of::<$target_ty>();
+
+        static ALIGNED: &$crate::ZerocopyAligned<$target_ty, [u8]> = &$crate::ZerocopyAligned {
+            _align: [],
+            bytes: *::core::include_bytes!($file),
+        };
+
+        let byte_slice: &[u8] = &ALIGNED.bytes;
+
+        assert!(
+            byte_slice.len() % SIZE == 0,
+            "Included file size is not divisible by target type size",
+        );
+
+        // Safety: the bytes are aligned for the target type, which is valid
+        // for any bit pattern and has no interior mutability.
+        let out_slice: &'static [$target_ty] = unsafe {
+            ::core::slice::from_raw_parts(byte_slice.as_ptr().cast(), byte_slice.len() / SIZE)
+        };
+
+        out_slice
+    }};
+}
@@ -0,0 +1,64 @@
+/// Include every file matching a glob pattern, as an `enum` with one variant
+/// per file.
+///
+/// `include_glob!(enum Name: T = "pattern")` defines an `enum Name` with one
+/// unit variant per file matched by `pattern`. Each variant's data is
+/// available as a `&'static [T]` through the `const fn get(self)` method, with
+/// exactly the same alignment and size guarantees as [`include_slice`](crate::include_slice). The
+/// set of variants is fixed at compile time, so a typo in an asset name is a
+/// compiler error rather than a failed lookup at runtime.
+///
+/// Variant names are the file stems (the file name up to the first `.`)
+/// converted to `UpperCamelCase`: `assets/tile_map-01.bin` becomes `TileMap01`.
+/// It is a compiler error if the pattern matches no files, if two files map to
+/// the same variant name, or if any matched file's size is not divisible by
+/// [`size_of::<T>()`][core::mem::size_of].
+///
+/// The generated `enum` also has:
+/// - `const ALL: [Self; N]` - every variant, in order of file path
+/// - `const fn path(self) -> &'static str` - the file's path, relative to the
+///   crate's manifest directory
+///
+/// Attributes (including doc comments) and a visibility may be given before
+/// the `enum` keyword. The `enum` derives `Clone`, `Copy`, `Debug`,
+/// `PartialEq`, `Eq`, `PartialOrd`, `Ord` and `Hash`.
+///
+/// Requires the `glob` feature.
+///
+/// # Paths
+///
+/// Unlike every other macro in this crate, the pattern is interpreted relative
+/// to the directory containing the crate's `Cargo.toml`, *not* relative to the
+/// file containing the macro call. Pattern syntax is that of the
+/// [`glob`](https://docs.rs/glob) crate.
+///
+/// Changes to any matched file will trigger a rebuild, but adding a new file
+/// which matches the pattern will not: `touch` the file containing the macro
+/// call (or run `cargo clean`) to pick it up.
+///
+/// # Example
+///
+/// ```
+/// # use include_data::include_glob;
+/// include_glob! {
+///     /// Every binary test file.
+///     pub enum TestData: u8 = "tests/test_data/binary_*";
+/// }
+///
+/// assert_eq!(TestData::Binary32.get().len(), 32);
+/// assert_eq!(TestData::Binary4.path(), "tests/test_data/binary_4");
+/// assert_eq!(TestData::ALL.len(), 7);
+/// ```
+///
+/// # Safety
+///
+/// This macro is safe. However, if used on a custom type, that type must
+/// implement [`bytemuck::AnyBitPattern`]. Implementing that trait has very
+/// strict safety requirements which must be observed.
+#[macro_export]
+#[allow(clippy::module_name_repetitions)]
+macro_rules! include_glob {
+    ($($input:tt)*) => {
+        $crate::__include_glob_impl! { $crate; $($input)* }
+    };
+}
@@ -0,0 +1,104 @@
+/// Include a file made of a fixed header followed by a slice of records, as a
+/// `(&'static H, &'static [T])` pair.
+///
+/// `include_header_slice!(H, T, path)` interprets the start of the file at
+/// `path` as a header of type `H`, and the rest as a slice of elements of type
+/// `T`. Both types must implement [`bytemuck::AnyBitPattern`], and the data
+/// is correctly aligned for both.
+///
+/// The slice starts at the first offset after the header which is aligned for
+/// `T`, exactly as the trailing field of a `#[repr(C)]` struct would: any
+/// padding bytes between the two are ignored. A compiler error will be thrown
+/// if the file is too short to contain the header, or if the size of the rest
+/// of the file is not divisible by [`size_of::<T>()`][core::mem::size_of].
+///
+/// If the header records the number of elements which follow it, giving that
+/// integer field's name with `include_header_slice!(H, T, path, count = field)`
+/// also checks at compile time that it matches the length of the slice.
+///
+/// This can assign to both `static` and `const` variables, and paths are
+/// interpreted by [`core::include_bytes`].
+///
+/// # Example
+///
+/// ```
+/// # use include_data::include_header_slice;
+/// #[repr(C)]
+/// #[derive(Copy, Clone)]
+/// struct Header {
+///     magic: [u8; 4],
+///     count: u32,
+/// }
+///
+/// // Safety: `Header` has no padding and only `AnyBitPattern` fields.
+/// unsafe impl bytemuck::Zeroable for Header {}
+/// unsafe impl bytemuck::AnyBitPattern for Header {}
+///
+/// static TABLE: (&Header, &[u32]) = include_header_slice!(
+///     Header,
+///     u32,
+///     "../tests/test_data/header_slice",
+///     count = count,
+/// );
+///
+/// let (header, records) = TABLE;
+/// assert_eq!(&header.magic, b"HDRS");
+/// assert_eq!(records.len(), 3);
+/// ```
+///
+/// # Safety
+///
+/// This macro is safe. However, if used on custom types, those types must
+/// implement [`bytemuck::AnyBitPattern`]. Implementing that trait has very
+/// strict safety requirements which must be observed.
+#[macro_export]
+macro_rules! include_header_slice {
+    ($header_ty:ty, $target_ty:ty, $file:expr $(, count = $count:ident)? $(,)?) => {{
+        const SIZE: usize = ::core::mem::size_of::<$target_ty>();
+        const ALIGN: usize = ::core::mem::align_of::<$target_ty>();
+        const OFFSET: usize = (::core::mem::size_of::<$header_ty>() + ALIGN - 1) / ALIGN * ALIGN;
+
+        const ALIGNED: &$crate::AlignedAs<$header_ty, $crate::AlignedAs<$target_ty, [u8]>> =
+            &$crate::AlignedAs {
+                _align: [],
+                bytes: $crate::AlignedAs {
+                    _align: [],
+                    bytes: *::core::include_bytes!($file),
+                },
+            };
+
+        const OUT: (&$header_ty, &[$target_ty]) = {
+            let byte_slice: &[u8] = &ALIGNED.bytes.bytes;
+
+            assert!(
+                byte_slice.len() >= OFFSET,
+                "Included file is too short for header type",
+            );
+            let rest = byte_slice.len() - OFFSET;
+            assert!(
+                rest % SIZE == 0,
+                "Included file size after header is not divisible by target type size",
+            );
+
+            let header: &$header_ty = unsafe { &*byte_slice.as_ptr().cast() };
+            let slice: &[$target_ty] = unsafe {
+                ::core::slice::from_raw_parts(byte_slice.as_ptr().add(OFFSET).cast(), rest / SIZE)
+            };
+
+            $(
+                assert!(
+                    header.$count as u64 == slice.len() as u64,
+                    ::core::concat!(
+                        "Header field `",
+                        ::core::stringify!($count),
+                        "` does not match the number of included elements",
+                    ),
+                );
+            )?
+
+            (header, slice)
+        };
+
+        OUT
+    }};
+}
@@ -0,0 +1,445 @@
+use core::fmt;
+use core::mem::size_of;
+use core::ops::Deref;
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::{Duration, Instant, SystemTime};
+use std::vec::Vec;
+
+/// How often [`HotSlice::current`] and [`HotData::current`] check whether the
+/// file has changed.
+const CHECK_INTERVAL: Duration = Duration::from_millis(100);
+
+/// A handle to a slice included with `include_slice!(T, path, hot)`, which is
+/// reloaded from disk at runtime when the file changes.
+///
+/// [`current`](Self::current) returns a [`Snapshot`] of the current version of
+/// the data, which does not change while it is held.
+///
+/// When `debug_assertions` are enabled, `current` checks whether the file has
+/// changed (by modification time and length) at most once every
+/// [`CHECK_INTERVAL`](Self::CHECK_INTERVAL), and if so reads it again with the
+/// same checks as [`include_slice`](crate::include_slice). The first call
+/// always reads the file. If the file cannot be read or is invalid, the
+/// previous version is kept and the error can be retrieved with
+/// [`take_error`](Self::take_error). Each version is freed once the handle and
+/// every snapshot of it have moved on.
+///
+/// Otherwise, the data embedded at compile time is always used, and the file
+/// is never read.
+///
+/// Requires the `hot-reload` feature.
+#[allow(clippy::module_name_repetitions)]
+pub struct HotSlice<T: 'static>(HotFile<[T]>);
+
+/// A handle to data included with `include_data!(path, hot)`, which is
+/// reloaded from disk at runtime when the file changes.
+///
+/// This behaves as [`HotSlice`], except that the file size must exactly match
+/// [`size_of::<T>()`][core::mem::size_of].
+///
+/// Requires the `hot-reload` feature.
+#[allow(clippy::module_name_repetitions)]
+pub struct HotData<T: 'static>(HotFile<T>);
+
+/// A version of the data of a [`HotSlice`] or [`HotData`], which dereferences
+/// to the data.
+///
+/// Either refers to the data embedded at compile time, or shares a version
+/// read at runtime with its handle. Cloning is cheap.
+pub struct Snapshot<T: ?Sized + 'static>(Version<T>);
+
+enum Version<T: ?Sized + 'static> {
+    Embedded(&'static T),
+    Loaded(Arc<T>),
+}
+
+impl<T: bytemuck::AnyBitPattern> HotSlice<T> {
+    /// How often [`current`](Self::current) checks whether the file has
+    /// changed.
+    pub const CHECK_INTERVAL: Duration = CHECK_INTERVAL;
+
+    /// Create a handle to the file at `path`, using `embedded` if it cannot be
+    /// read.
+    ///
+    /// A relative `path` is interpreted relative to the working directory at
+    /// runtime. Usually created with `include_slice!(T, path, hot)`.
+    ///
+    /// # Panics
+    ///
+    /// If `T` is zero-sized. Since handles are created in `static`
+    /// initializers, this is usually a compiler error.
+    #[must_use]
+    pub const fn new(path: &'static str, embedded: &'static [T]) -> Self {
+        assert_not_zero_sized::<T>();
+        Self(HotFile::new(None, path, embedded))
+    }
+
+    /// As [`new`](Self::new), but with `path` relative to the directory
+    /// containing `source`.
+    #[doc(hidden)]
+    #[must_use]
+    pub const fn relative_to(
+        source: &'static str,
+        path: &'static str,
+        embedded: &'static [T],
+    ) -> Self {
+        assert_not_zero_sized::<T>();
+        Self(HotFile::new(Some(source), path, embedded))
+    }
+
+    /// A snapshot of the current data, after reading the file again if it has
+    /// changed.
+    #[must_use]
+    pub fn current(&self) -> Snapshot<[T]> {
+        self.0.current()
+    }
+
+    /// Check for changes to the file now, rather than waiting for
+    /// [`current`](Self::current) to do so, returning whether it was reloaded.
+    ///
+    /// # Errors
+    ///
+    /// If the file cannot be read, or its size is not divisible by
+    /// [`size_of::<T>()`][core::mem::size_of]. The current data is then left
+    /// unchanged.
+    pub fn reload(&self) -> io::Result<bool> {
+        self.0.reload()
+    }
+
+    /// The error from the last check for changes by
+    /// [`current`](Self::current), if it failed, clearing it.
+    pub fn take_error(&self) -> Option<io::Error> {
+        self.0.take_error()
+    }
+
+    /// The path of the file.
+    #[must_use]
+    pub fn path(&self) -> PathBuf {
+        self.0.path()
+    }
+}
+
+impl<T: bytemuck::AnyBitPattern> HotData<T> {
+    /// How often [`current`](Self::current) checks whether the file has
+    /// changed.
+    pub const CHECK_INTERVAL: Duration = CHECK_INTERVAL;
+
+    /// Create a handle to the file at `path`, using `embedded` if it cannot be
+    /// read.
+    ///
+    /// A relative `path` is interpreted relative to the working directory at
+    /// runtime. Usually created with `include_data!(path, hot)`.
+    ///
+    /// # Panics
+    ///
+    /// If `T` is zero-sized. Since handles are created in `static`
+    /// initializers, this is usually a compiler error.
+    #[must_use]
+    pub const fn new(path: &'static str, embedded: &'static T) -> Self {
+        assert_not_zero_sized::<T>();
+        Self(HotFile::new(None, path, embedded))
+    }
+
+    /// As [`new`](Self::new), but with `path` relative to the directory
+    /// containing `source`.
+    #[doc(hidden)]
+    #[must_use]
+    pub const fn relative_to(
+        source: &'static str,
+        path: &'static str,
+        embedded: &'static T,
+    ) -> Self {
+        assert_not_zero_sized::<T>();
+        Self(HotFile::new(Some(source), path, embedded))
+    }
+
+    /// A snapshot of the current data, after reading the file again if it has
+    /// changed.
+    #[must_use]
+    pub fn current(&self) -> Snapshot<T> {
+        self.0.current()
+    }
+
+    /// Check for changes to the file now, rather than waiting for
+    /// [`current`](Self::current) to do so, returning whether it was reloaded.
+    ///
+    /// # Errors
+    ///
+    /// If the file cannot be read, or its size does not match
+    /// [`size_of::<T>()`][core::mem::size_of]. The current data is then left
+    /// unchanged.
+    pub fn reload(&self) -> io::Result<bool> {
+        self.0.reload()
+    }
+
+    /// The error from the last check for changes by
+    /// [`current`](Self::current), if it failed, clearing it.
+    pub fn take_error(&self) -> Option<io::Error> {
+        self.0.take_error()
+    }
+
+    /// The path of the file.
+    #[must_use]
+    pub fn path(&self) -> PathBuf {
+        self.0.path()
+    }
+}
+
+impl<T: bytemuck::AnyBitPattern + fmt::Debug> fmt::Debug for HotSlice<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&*self.current(), f)
+    }
+}
+
+impl<T: bytemuck::AnyBitPattern + fmt::Debug> fmt::Debug for HotData<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&*self.current(), f)
+    }
+}
+
+impl<T: ?Sized> Deref for Snapshot<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        match &self.0 {
+            Version::Embedded(data) => data,
+            Version::Loaded(data) => data,
+        }
+    }
+}
+
+impl<T: ?Sized> Clone for Snapshot<T> {
+    fn clone(&self) -> Self {
+        Self(match &self.0 {
+            Version::Embedded(data) => Version::Embedded(data),
+            Version::Loaded(data) => Version::Loaded(Arc::clone(data)),
+        })
+    }
+}
+
+impl<T: ?Sized + fmt::Debug> fmt::Debug for Snapshot<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+/// The contents of a file included with hot reloading: either a single value
+/// or a slice.
+trait Contents: 'static {
+    /// Check the size of the file, and copy its bytes into aligned memory.
+    fn from_bytes(bytes: &[u8]) -> io::Result<Arc<Self>>;
+}
+
+impl<T: bytemuck::AnyBitPattern> Contents for T {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Arc<Self>> {
+        if bytes.len() != size_of::<T>() {
+            return Err(invalid_data("file size does not match target type size"));
+        }
+        Ok(Arc::new(bytemuck::pod_read_unaligned(bytes)))
+    }
+}
+
+impl<T: bytemuck::AnyBitPattern> Contents for [T] {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Arc<Self>> {
+        let size = size_of::<T>();
+        if size == 0 {
+            return Err(invalid_data("target type is zero-sized"));
+        }
+        if bytes.len() % size != 0 {
+            return Err(invalid_data(
+                "file size is not divisible by target type size",
+            ));
+        }
+
+        let data: Vec<T> = bytes
+            .chunks_exact(size)
+            .map(bytemuck::pod_read_unaligned)
+            .collect();
+        Ok(data.into())
+    }
+}
+
+/// The value of an included file, as [`include_data`](crate::include_data).
+///
+/// Unlike that macro, this is a single `const fn` call, so a reference to the
+/// result in a `static` initializer is promoted to a `&'static T`.
+#[doc(hidden)]
+#[must_use]
+pub const fn read_included<T: bytemuck::AnyBitPattern, const N: usize>(bytes: &[u8; N]) -> T {
+    #[repr(C)]
+    union Bytes<T: Copy, const N: usize> {
+        bytes: [u8; N],
+        value: T,
+    }
+
+    assert!(
+        size_of::<T>() == N,
+        "Included file size does not match target type size",
+    );
+    // Safety: the sizes match, and every bit pattern is valid for
+    // `T: AnyBitPattern`.
+    unsafe { Bytes::<T, N> { bytes: *bytes }.value }
+}
+
+/// Reject zero-sized types, whose files could not be checked: any number of
+/// values would fit in an empty file.
+const fn assert_not_zero_sized<T>() {
+    assert!(
+        size_of::<T>() != 0,
+        "Hot reloading does not support zero-sized types",
+    );
+}
+
+fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+/// Shared implementation of [`HotSlice`] and [`HotData`].
+struct HotFile<S: ?Sized + 'static> {
+    /// The file containing the macro call, if `path` is relative to it.
+    source: Option<&'static str>,
+    path: &'static str,
+    embedded: &'static S,
+    state: Mutex<State<S>>,
+}
+
+/// The current version of a file.
+struct State<S: ?Sized + 'static> {
+    /// `None` until the file is first read.
+    loaded: Option<Arc<S>>,
+    /// Modification time and length of the file when it was last read.
+    stamp: Option<(SystemTime, u64)>,
+    /// When `current` last checked for changes.
+    checked: Option<Instant>,
+    error: Option<io::Error>,
+}
+
+impl<S: ?Sized + Contents> HotFile<S> {
+    const fn new(source: Option<&'static str>, path: &'static str, embedded: &'static S) -> Self {
+        Self {
+            source,
+            path,
+            embedded,
+            state: Mutex::new(State {
+                loaded: None,
+                stamp: None,
+                checked: None,
+                error: None,
+            }),
+        }
+    }
+
+    fn path(&self) -> PathBuf {
+        match self.source.and_then(|source| Path::new(source).parent()) {
+            Some(dir) => dir.join(self.path),
+            None => PathBuf::from(self.path),
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, State<S>> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn current(&self) -> Snapshot<S> {
+        if !cfg!(debug_assertions) {
+            return Snapshot(Version::Embedded(self.embedded));
+        }
+
+        let mut state = self.lock();
+        let now = Instant::now();
+        let due = state.checked.map_or(true, |checked| {
+            now.duration_since(checked) >= CHECK_INTERVAL
+        });
+        if due {
+            state.checked = Some(now);
+            if let Err(err) = self.reload_locked(&mut state) {
+                state.error = Some(err);
+            }
+        }
+
+        Snapshot(match &state.loaded {
+            Some(loaded) => Version::Loaded(Arc::clone(loaded)),
+            None => Version::Embedded(self.embedded),
+        })
+    }
+
+    fn reload(&self) -> io::Result<bool> {
+        if !cfg!(debug_assertions) {
+            return Ok(false);
+        }
+
+        let mut state = self.lock();
+        state.checked = Some(Instant::now());
+        self.reload_locked(&mut state)
+    }
+
+    fn take_error(&self) -> Option<io::Error> {
+        self.lock().error.take()
+    }
+
+    /// Read the file if it has changed since it was last read.
+    fn reload_locked(&self, state: &mut State<S>) -> io::Result<bool> {
+        let path = self.path();
+        let meta = fs::metadata(&path)?;
+        let stamp = Some((meta.modified()?, meta.len()));
+        if state.stamp == stamp {
+            return Ok(false);
+        }
+
+        state.loaded = Some(S::from_bytes(&fs::read(&path)?)?);
+        state.stamp = stamp;
+        Ok(true)
+    }
+}
+
+/// [`include_slice`](crate::include_slice) with `hot`.
+#[doc(hidden)]
+#[macro_export]
+#[allow(clippy::module_name_repetitions)]
+macro_rules! __include_slice_hot {
+    ($target_ty:ty, $file:expr) => {{
+        const EMBEDDED: &[$target_ty] = $crate::__include_slice_const!($target_ty, $file);
+
+        $crate::HotSlice::relative_to(
+            ::core::concat!($crate::__current_dir_impl!(), "/", ::core::file!()),
+            $file,
+            EMBEDDED,
+        )
+    }};
+    ($target_ty:ty, $file:expr, from = $root:ident $(($var:literal))?) => {{
+        const EMBEDDED: &[$target_ty] = $crate::__include_slice_const!(
+            $target_ty,
+            $crate::__resolve_path!($root $(($var))?, $file)
+        );
+
+        $crate::HotSlice::new($crate::__resolve_path!($root $(($var))?, $file), EMBEDDED)
+    }};
+}
+
+/// [`include_data`](crate::include_data) with `hot`.
+#[doc(hidden)]
+#[macro_export]
+#[allow(clippy::module_name_repetitions)]
+macro_rules! __include_data_hot {
+    ($file:expr) => {
+        // The type of the embedded value is only known from the `static`, so
+        // it cannot be named in a `const`, and is promoted instead.
+        $crate::HotData::relative_to(
+            ::core::concat!($crate::__current_dir_impl!(), "/", ::core::file!()),
+            $file,
+            &$crate::read_included(::core::include_bytes!($file)),
+        )
+    };
+    ($file:expr, from = $root:ident $(($var:literal))?) => {
+        $crate::HotData::new(
+            $crate::__resolve_path!($root $(($var))?, $file),
+            &$crate::read_included(::core::include_bytes!(
+                $crate::__resolve_path!($root $(($var))?, $file)
+            )),
+        )
+    };
+}
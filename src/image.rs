@@ -0,0 +1,486 @@
+use embedded_graphics::pixelcolor::raw::{BigEndian, ByteOrder, LittleEndian, RawData};
+use embedded_graphics::pixelcolor::{
+    Bgr555, Bgr565, Bgr666, Bgr888, BinaryColor, Gray2, Gray4, Gray8, PixelColor, Rgb555, Rgb565,
+    Rgb666, Rgb888,
+};
+
+use crate::Endian;
+
+/// The byte order of an `ImageRaw`, as an [`Endian`].
+#[doc(hidden)]
+#[allow(clippy::module_name_repetitions)]
+pub trait ImageByteOrder: ByteOrder {
+    const ENDIAN: Endian;
+}
+
+impl ImageByteOrder for BigEndian {
+    const ENDIAN: Endian = Endian::Big;
+}
+
+impl ImageByteOrder for LittleEndian {
+    const ENDIAN: Endian = Endian::Little;
+}
+
+/// The layout of the raw data of a color type.
+#[doc(hidden)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PixelFormat {
+    /// One bit per pixel.
+    Binary,
+    /// A gray level of the given number of bits.
+    Gray(u32),
+    /// Red, green and blue channels of the given numbers of bits, with red in
+    /// the most significant bits.
+    Rgb(u32, u32, u32),
+    /// Red, green and blue channels of the given numbers of bits, with blue in
+    /// the most significant bits.
+    Bgr(u32, u32, u32),
+}
+
+/// `embedded-graphics` color types which can be decoded from Netpbm images
+/// with [`include_netpbm`](crate::include_netpbm).
+///
+/// [`BinaryColor`] is decoded from PBM images, [`Gray2`], [`Gray4`] and
+/// [`Gray8`] from PGM images, and the RGB and BGR color types from PPM images.
+pub trait NetpbmColor: PixelColor + From<<Self as PixelColor>::Raw> {
+    #[doc(hidden)]
+    const FORMAT: PixelFormat;
+}
+
+macro_rules! impl_netpbm_color {
+    ($($ty:ty => $format:expr;)*) => {
+        $(
+            impl NetpbmColor for $ty {
+                const FORMAT: PixelFormat = $format;
+            }
+        )*
+    };
+}
+
+impl_netpbm_color! {
+    BinaryColor => PixelFormat::Binary;
+    Gray2 => PixelFormat::Gray(2);
+    Gray4 => PixelFormat::Gray(4);
+    Gray8 => PixelFormat::Gray(8);
+    Rgb555 => PixelFormat::Rgb(5, 5, 5);
+    Rgb565 => PixelFormat::Rgb(5, 6, 5);
+    Rgb666 => PixelFormat::Rgb(6, 6, 6);
+    Rgb888 => PixelFormat::Rgb(8, 8, 8);
+    Bgr555 => PixelFormat::Bgr(5, 5, 5);
+    Bgr565 => PixelFormat::Bgr(5, 6, 5);
+    Bgr666 => PixelFormat::Bgr(6, 6, 6);
+    Bgr888 => PixelFormat::Bgr(8, 8, 8);
+}
+
+/// The number of bits per pixel of the color type `C`.
+const fn bits_per_pixel<C: PixelColor>() -> usize {
+    <C::Raw as RawData>::BITS_PER_PIXEL
+}
+
+/// The length in bytes of each row of an image of color type `C`, which is
+/// padded to a whole byte.
+const fn row_len<C: PixelColor>(width: usize) -> usize {
+    (width * bits_per_pixel::<C>() + 7) / 8
+}
+
+/// Check that `bytes` is a whole number of rows of an image of color type `C`
+/// and the given width, returning the width.
+#[doc(hidden)]
+#[must_use]
+#[allow(clippy::module_name_repetitions)]
+pub const fn image_width<C: PixelColor>(bytes: &[u8], width: u32) -> u32 {
+    assert!(width > 0, "Image width must not be zero");
+    assert!(
+        bytes.len() % row_len::<C>(width as usize) == 0,
+        "Included file size is not a whole number of image rows",
+    );
+    width
+}
+
+/// The parts of a Netpbm header needed to decode its image.
+#[derive(Clone, Copy)]
+struct NetpbmHeader {
+    format: PixelFormat,
+    width: usize,
+    height: usize,
+    max_value: u32,
+    data_start: usize,
+}
+
+impl NetpbmHeader {
+    /// The number of bytes of each sample.
+    const fn sample_len(&self) -> usize {
+        if self.max_value > 255 {
+            2
+        } else {
+            1
+        }
+    }
+
+    /// The value of `channel` of the pixel at `x`, `y`, scaled to `bits` bits.
+    const fn sample(&self, file: &[u8], x: usize, y: usize, channel: usize, bits: u32) -> u32 {
+        if let PixelFormat::Binary = self.format {
+            let byte = file[self.data_start + y * ((self.width + 7) / 8) + x / 8];
+            return (byte >> (7 - x % 8)) as u32 & 1;
+        }
+        let channels = if let PixelFormat::Gray(_) = self.format {
+            1
+        } else {
+            3
+        };
+        let pos = self.data_start + ((y * self.width + x) * channels + channel) * self.sample_len();
+        let value = if self.sample_len() == 2 {
+            (file[pos] as u32) << 8 | file[pos + 1] as u32
+        } else {
+            file[pos] as u32
+        };
+        assert!(
+            value <= self.max_value,
+            "Included image has a sample greater than its maximum value",
+        );
+        // Rounded in the same way as conversions between `embedded-graphics`
+        // color types.
+        let max = (1 << bits) - 1;
+        (value * max + self.max_value / 2) / self.max_value
+    }
+}
+
+/// Parse the header of a binary PBM (`P4`), PGM (`P5`) or PPM (`P6`) image,
+/// as described at <https://netpbm.sourceforge.net/doc/pbm.html> and the
+/// neighbouring pages.
+const fn parse_netpbm(file: &[u8]) -> NetpbmHeader {
+    assert!(
+        file.len() >= 2 && file[0] == b'P',
+        "Included file is not a Netpbm image",
+    );
+    let format = match file[1] {
+        b'4' => PixelFormat::Binary,
+        b'5' => PixelFormat::Gray(0),
+        b'6' => PixelFormat::Rgb(0, 0, 0),
+        b'1' | b'2' | b'3' => {
+            panic!("Included Netpbm image is in the plain format, which is not supported")
+        }
+        _ => panic!("Included file is not a Netpbm image"),
+    };
+
+    let (width, pos) = header_number(file, 2);
+    let (height, mut pos) = header_number(file, pos);
+    let mut max_value = 1;
+    if !matches!(format, PixelFormat::Binary) {
+        let max;
+        (max, pos) = header_number(file, pos);
+        assert!(
+            max > 0 && max < 65536,
+            "Included Netpbm image has an invalid maximum value",
+        );
+        #[allow(clippy::cast_possible_truncation)]
+        let max = max as u32;
+        max_value = max;
+    }
+    assert!(
+        pos < file.len() && file[pos].is_ascii_whitespace(),
+        "Included Netpbm header is truncated",
+    );
+    let header = NetpbmHeader {
+        format,
+        width,
+        height,
+        max_value,
+        data_start: pos + 1,
+    };
+
+    let data_len = match format {
+        PixelFormat::Binary => (width + 7) / 8 * height,
+        PixelFormat::Gray(_) => width * height * header.sample_len(),
+        PixelFormat::Rgb(..) | PixelFormat::Bgr(..) => width * height * 3 * header.sample_len(),
+    };
+    assert!(
+        file.len() - header.data_start == data_len,
+        "Included image data size does not match its header",
+    );
+    header
+}
+
+/// The number in the Netpbm header `file` after `pos`, skipping whitespace
+/// and comments, and the position after it.
+const fn header_number(file: &[u8], mut pos: usize) -> (usize, usize) {
+    while pos < file.len() && (file[pos].is_ascii_whitespace() || file[pos] == b'#') {
+        if file[pos] == b'#' {
+            while pos < file.len() && file[pos] != b'\n' && file[pos] != b'\r' {
+                pos += 1;
+            }
+        } else {
+            pos += 1;
+        }
+    }
+    assert!(
+        pos < file.len() && file[pos].is_ascii_digit(),
+        "Included Netpbm header is invalid",
+    );
+    let mut number: usize = 0;
+    while pos < file.len() && file[pos].is_ascii_digit() {
+        number = match number.checked_mul(10) {
+            Some(number) => number + (file[pos] - b'0') as usize,
+            None => panic!("Included Netpbm header is invalid"),
+        };
+        pos += 1;
+    }
+    (number, pos)
+}
+
+/// Parse the header of a Netpbm image to be decoded as color type `C`.
+const fn netpbm_header<C: NetpbmColor>(file: &[u8]) -> NetpbmHeader {
+    let header = parse_netpbm(file);
+    match (C::FORMAT, header.format) {
+        (PixelFormat::Binary, PixelFormat::Binary)
+        | (PixelFormat::Gray(_), PixelFormat::Gray(_))
+        | (PixelFormat::Rgb(..) | PixelFormat::Bgr(..), PixelFormat::Rgb(..)) => header,
+        (PixelFormat::Binary, _) => panic!("Included image must be a PBM image for `BinaryColor`"),
+        (PixelFormat::Gray(_), _) => {
+            panic!("Included image must be a PGM image for a grayscale color type")
+        }
+        (PixelFormat::Rgb(..) | PixelFormat::Bgr(..), _) => {
+            panic!("Included image must be a PPM image for an RGB color type")
+        }
+    }
+}
+
+/// The width of the Netpbm image `file`.
+#[doc(hidden)]
+#[must_use]
+pub const fn netpbm_width<C: NetpbmColor>(file: &[u8]) -> u32 {
+    let width = netpbm_header::<C>(file).width;
+    assert!(width > 0, "Image width must not be zero");
+    assert!(width <= u32::MAX as usize, "Included image is too wide");
+    #[allow(clippy::cast_possible_truncation)]
+    let width = width as u32;
+    width
+}
+
+/// The length of the raw data of the Netpbm image `file` as color type `C`.
+#[doc(hidden)]
+#[must_use]
+pub const fn netpbm_len<C: NetpbmColor>(file: &[u8]) -> usize {
+    let header = netpbm_header::<C>(file);
+    row_len::<C>(header.width) * header.height
+}
+
+/// Decode the Netpbm image `file` to the raw data of color type `C`, in the
+/// byte order `BO`.
+#[doc(hidden)]
+#[must_use]
+pub const fn decode_netpbm<C: NetpbmColor, BO: ImageByteOrder, const N: usize>(
+    file: &[u8],
+) -> [u8; N] {
+    let header = netpbm_header::<C>(file);
+    let bits = bits_per_pixel::<C>();
+    let row_len = row_len::<C>(header.width);
+    assert!(
+        row_len * header.height == N,
+        "Wrong length of decoded image"
+    );
+
+    let mut out = [0; N];
+    let mut y = 0;
+    while y < header.height {
+        let mut x = 0;
+        while x < header.width {
+            let value = match C::FORMAT {
+                PixelFormat::Binary => header.sample(file, x, y, 0, 1),
+                PixelFormat::Gray(depth) => header.sample(file, x, y, 0, depth),
+                PixelFormat::Rgb(red, green, blue) => {
+                    header.sample(file, x, y, 0, red) << (green + blue)
+                        | header.sample(file, x, y, 1, green) << blue
+                        | header.sample(file, x, y, 2, blue)
+                }
+                PixelFormat::Bgr(red, green, blue) => {
+                    header.sample(file, x, y, 2, blue) << (green + red)
+                        | header.sample(file, x, y, 1, green) << red
+                        | header.sample(file, x, y, 0, red)
+                }
+            };
+
+            if bits < 8 {
+                // Pixels are packed into bytes, most significant bits first.
+                let bit = x * bits;
+                #[allow(clippy::cast_possible_truncation)]
+                let byte = (value << (8 - bits - bit % 8)) as u8;
+                out[y * row_len + bit / 8] |= byte;
+            } else {
+                let bytes = bits / 8;
+                let start = y * row_len + x * bytes;
+                let mut i = 0;
+                while i < bytes {
+                    let shift = match BO::ENDIAN {
+                        Endian::Big => 8 * (bytes - 1 - i),
+                        Endian::Little => 8 * i,
+                    };
+                    #[allow(clippy::cast_possible_truncation)]
+                    let byte = (value >> shift) as u8;
+                    out[start + i] = byte;
+                    i += 1;
+                }
+            }
+            x += 1;
+        }
+        y += 1;
+    }
+    out
+}
+
+/// Include a file of raw pixel data as an `embedded-graphics`
+/// [`ImageRaw`](embedded_graphics::image::ImageRaw).
+///
+/// `include_image_raw!(C, width, path)` includes the file at `path` as an
+/// `ImageRaw<'static, C>` of the given width, which may be a `const`. The
+/// height is the number of rows in the file. Pixels are read in the format of
+/// `C::Raw`, with rows padded to a whole byte for color types of less than 8
+/// bits, such as `BinaryColor`.
+///
+/// Pixels of more than one byte are read as big-endian by default, as for
+/// `ImageRaw`. The option `endian = little` instead includes the file as an
+/// `ImageRaw<'static, C, LittleEndian>`, and `endian = big` can be given to
+/// be explicit.
+///
+/// A compiler error will be thrown if the width is zero or the file size is
+/// not a whole number of rows. This can assign to both `static` and `const`
+/// variables, and paths are interpreted by [`core::include_bytes`], or
+/// resolved with a `from = ...` argument (given before any options) as for
+/// [`include_data`](crate::include_data#path-roots).
+///
+/// # Example
+///
+/// ```
+/// # use include_data::include_image_raw;
+/// use embedded_graphics::image::{GetPixel, ImageRaw};
+/// use embedded_graphics::pixelcolor::raw::{LittleEndian, RawU16};
+/// use embedded_graphics::pixelcolor::Rgb565;
+/// use embedded_graphics::prelude::*;
+///
+/// const WIDTH: u32 = 4;
+/// static SPLASH: ImageRaw<Rgb565, LittleEndian> =
+///     include_image_raw!(Rgb565, WIDTH, "../tests/test_data/binary_32", endian = little);
+///
+/// assert_eq!(SPLASH.size(), Size::new(4, 4));
+/// assert_eq!(
+///     SPLASH.pixel(Point::new(1, 0)),
+///     Some(Rgb565::from(RawU16::new(u16::from_le_bytes([2, 3])))),
+/// );
+/// ```
+#[macro_export]
+macro_rules! include_image_raw {
+    ($color:ty, $width:expr, $file:expr, from = $root:ident $(($var:literal))? $(, $($options:tt)*)?) => {
+        $crate::include_image_raw!(
+            $color,
+            $width,
+            $crate::__resolve_path!($root $(($var))?, $file)
+            $(, $($options)*)?
+        )
+    };
+    ($color:ty, $width:expr, $file:expr $(, $option:ident = $value:tt)* $(,)?) => {{
+        const BYTES: &[u8] = ::core::include_bytes!($file);
+        const IMAGE: $crate::embedded_graphics::image::ImageRaw<
+            'static,
+            $color,
+            $crate::__image_byte_order!($($option = $value),*),
+        > = $crate::embedded_graphics::image::ImageRaw::new(
+            BYTES,
+            $crate::image_width::<$color>(BYTES, $width),
+        );
+
+        IMAGE
+    }};
+}
+
+/// Decode a binary Netpbm image (PBM, PGM or PPM) at compile time as an
+/// `embedded-graphics` [`ImageRaw`](embedded_graphics::image::ImageRaw).
+///
+/// `include_netpbm!(C, path)` reads the width and height of the image from the
+/// file at `path`, and converts each pixel to the color type `C`, which must
+/// implement [`NetpbmColor`]. Only the converted pixel data is included in the
+/// executable. Channels are scaled to the depth of `C` with rounding, in the
+/// same way as conversions between `embedded-graphics` color types, and
+/// 16-bit samples are supported.
+///
+/// `BinaryColor` images are decoded from PBM (`P4`) files, in which `1` is
+/// black: black pixels become `BinaryColor::On`. Grayscale color types are
+/// decoded from PGM (`P5`) files, and RGB and BGR color types from PPM (`P6`)
+/// files. The plain (ASCII) Netpbm formats are not supported.
+///
+/// Pixels of more than one byte are stored big-endian by default, and the
+/// option `endian = little` instead gives an
+/// `ImageRaw<'static, C, LittleEndian>`, as for
+/// [`include_image_raw`](crate::include_image_raw).
+///
+/// A compiler error will be thrown if the file is not a Netpbm image of the
+/// kind required by `C`, or if its data does not match its header. This can
+/// assign to both `static` and `const` variables, and paths are interpreted by
+/// [`core::include_bytes`], or resolved with a `from = ...` argument (given
+/// before any options) as for
+/// [`include_data`](crate::include_data#path-roots).
+///
+/// # Example
+///
+/// ```
+/// # use include_data::include_netpbm;
+/// use embedded_graphics::image::{GetPixel, ImageRaw};
+/// use embedded_graphics::pixelcolor::{Rgb565, Rgb888};
+/// use embedded_graphics::prelude::*;
+///
+/// static ICON: ImageRaw<Rgb565> = include_netpbm!(Rgb565, "../tests/test_data/image_3x2.ppm");
+///
+/// assert_eq!(ICON.size(), Size::new(3, 2));
+/// assert_eq!(ICON.pixel(Point::new(0, 0)), Some(Rgb565::RED));
+/// assert_eq!(
+///     ICON.pixel(Point::new(2, 1)),
+///     Some(Rgb565::from(Rgb888::new(100, 150, 200))),
+/// );
+/// ```
+#[macro_export]
+macro_rules! include_netpbm {
+    ($color:ty, $file:expr, from = $root:ident $(($var:literal))? $(, $($options:tt)*)?) => {
+        $crate::include_netpbm!(
+            $color,
+            $crate::__resolve_path!($root $(($var))?, $file)
+            $(, $($options)*)?
+        )
+    };
+    ($color:ty, $file:expr $(, $option:ident = $value:tt)* $(,)?) => {{
+        type ByteOrder = $crate::__image_byte_order!($($option = $value),*);
+        const FILE: &[u8] = ::core::include_bytes!($file);
+        const LEN: usize = $crate::netpbm_len::<$color>(FILE);
+        const PIXELS: &[u8; LEN] = &$crate::decode_netpbm::<$color, ByteOrder, LEN>(FILE);
+        const IMAGE: $crate::embedded_graphics::image::ImageRaw<'static, $color, ByteOrder> =
+            $crate::embedded_graphics::image::ImageRaw::new(
+                PIXELS,
+                $crate::netpbm_width::<$color>(FILE),
+            );
+
+        IMAGE
+    }};
+}
+
+/// The byte order type given by the options of
+/// [`include_image_raw`](crate::include_image_raw) or
+/// [`include_netpbm`](crate::include_netpbm).
+#[doc(hidden)]
+#[macro_export]
+#[allow(clippy::module_name_repetitions)]
+macro_rules! __image_byte_order {
+    () => {
+        $crate::embedded_graphics::pixelcolor::raw::BigEndian
+    };
+    (endian = big) => {
+        $crate::embedded_graphics::pixelcolor::raw::BigEndian
+    };
+    (endian = little) => {
+        $crate::embedded_graphics::pixelcolor::raw::LittleEndian
+    };
+    ($($option:ident = $value:tt),*) => {
+        ::core::compile_error!(::core::concat!(
+            "Unknown options `",
+            ::core::stringify!($($option = $value),*),
+            "`, expected `endian = little` or `endian = big`",
+        ))
+    };
+}
@@ -24,6 +24,24 @@
 //! Note that `include_data` works with `const`, while `include_slice` only
 //! supports `static`.
 //!
+//! Several files can be included as one contiguous slice with
+//! [`include_slice_concat`], or as the columns of a struct of slices of the
+//! same length with [`include_columns`]. Matrix and image-like data can be
+//! included as slices of rows with [`include_matrix`], or of N-dimensional
+//! arrays with [`include_shaped`]. Files made of a fixed header followed by
+//! a slice of records can be included with [`include_header_slice`], and
+//! files of variable-length records, such as string tables, with
+//! [`include_records`]. Text files can be included as a table of strings,
+//! one per line, with [`include_lines`]. Files of key/value records can be
+//! included as a [`StaticMap`], built at compile time, with [`include_map`].
+//! Tables which must be sorted can be included with [`include_sorted_slice`],
+//! which checks their order at compile time. Booleans can be included packed
+//! eight to a byte as a [`BitSlice`] with [`include_bits`], and integers of
+//! any width up to 32 bits, such as 12-bit samples, as a [`PackedSlice`] with
+//! [`include_packed`]. Fixed-point tables, such as DSP coefficients, can be
+//! included as [`Q15`] or [`Q31`] values from decimal text with
+//! [`include_decimals`], which checks that every value is in range.
+//!
 //! For custom types:
 //! ```
 //! # use include_data::include_data;
@@ -41,6 +59,8 @@
 //!
 //! static FOO_DATA: Foo = include_data!("../tests/test_data/file_exactly_4_bytes_long");
 //! ```
+//! With the `derive` feature, the `unsafe impl`s can be replaced with
+//! `#[derive(IncludeData)]`, which checks these requirements at compile time.
 //!
 //! If necessary, this crate also provides the [`include_unsafe`] macro,
 //! which is sound if and only if the included file is a valid bit pattern
@@ -62,6 +82,61 @@
 //! static BAR_DATA: StructWithBool = unsafe { include_unsafe!("../tests/test_data/file_exactly_4_bytes_long") };
 //! ```
 //!
+//! Files in the self-describing format described by [`TypedHeader`] can be
+//! included with [`include_typed`], which also checks at compile time that the
+//! element type, size, count and endianness recorded in the file match.
+//!
+//! To generate data files from a build script, see the companion crate
+//! `include_data_build`.
+//!
+//! ## Optional features
+//!
+//! None of these are enabled by default.
+//!
+//! - `derive` - enables `#[derive(IncludeData)]`, which implements the
+//!   `bytemuck` traits required by this crate after checking at compile time
+//!   that doing so is sound, and `#[derive(LayoutFingerprint)]`. This requires
+//!   a procedural macro dependency.
+//! - `embedded-graphics` - enables `include_image_raw` and `include_netpbm`,
+//!   which include raw pixel data or decode a Netpbm image at compile time as
+//!   an `embedded-graphics` `ImageRaw`.
+//! - `fallback` - enables `include_slice_or`, `include_data_or` and
+//!   `include_data_or_default`, which fall back to a default value if the
+//!   file does not exist, and `file_exists`.
+//! - `glam` - implements the `bytemuck` traits for `glam` types, so that they
+//!   can be included with [`include_slice`], and enables `include_padded`,
+//!   which includes packed data as types with SIMD padding such as `Vec3A`.
+//!   This does not choose a math backend for `glam`, which needs either its
+//!   `std` or its `libm` feature: `glam-libm` enables `glam` with `libm`, for
+//!   `no_std` crates which do not depend on `glam` themselves.
+//! - `glob` - enables `include_glob`, which includes every file matching a
+//!   glob pattern as an `enum` with one variant per file.
+//! - `half` - enables `include_f16s` and `include_bf16s`, aliases of
+//!   [`include_slice`] for the half-precision float types of the `half` crate,
+//!   and `include_f16s_le`, `include_f16s_be`, `include_bf16s_le` and
+//!   `include_bf16s_be`, which include files with a fixed byte order. This
+//!   implements the `bytemuck` traits for those types.
+//! - `hot-reload` - enables a `hot` argument to [`include_slice`] and
+//!   [`include_data`], which makes them evaluate to handles that reload
+//!   included files from disk at runtime when they change, in builds with
+//!   `debug_assertions`. This requires `std`.
+//! - `mint` - enables `include_mint`, which includes slices of `mint` types
+//!   such as `mint::Vector3<f32>`.
+//! - `mmap` - enables `IncludedSlice`, which holds data either embedded with
+//!   `include_slice` or memory-mapped from a file at runtime, so that large
+//!   data sets can be moved out of the executable. This requires `std`.
+//! - `nalgebra` - implements the `bytemuck` traits for `nalgebra` types with
+//!   a fixed size, such as `Point3<f32>` and `Matrix4<f32>`, so that they can
+//!   be included with [`include_slice`].
+//! - `ndarray` - enables `include_ndarray` and `include_npy`, which include
+//!   a file with a given shape or an `.npy` file from `numpy` as a
+//!   `StaticArray`, which can be viewed as an `ndarray` array without copying.
+//! - `nightly` - makes the `fallback` macros rebuild the crate when a missing
+//!   file is added. This requires a nightly compiler.
+//! - `zerocopy` - allows [`include_data`] and [`include_slice`] to include
+//!   types which implement the `zerocopy` traits instead of the `bytemuck`
+//!   traits, with a `via = zerocopy` argument.
+//!
 //! ## Platform-specific behaviour
 //!
 //! The interpretation of multi-byte sequences depends on a machine's
@@ -71,9 +146,268 @@
 //!
 //! The interpreation of paths passed to these macros is host-platform specific
 //! and identical to that of [`core::include_bytes`].
+//! Paths can instead be resolved relative to the crate's manifest directory,
+//! the build script's `OUT_DIR`, or other roots: see
+//! [`include_data`](crate::include_data#path-roots).
+
+#[cfg(any(feature = "hot-reload", feature = "mmap"))]
+extern crate std;
 
 #[doc(hidden)]
 pub use bytemuck;
+#[cfg(feature = "embedded-graphics")]
+#[doc(hidden)]
+pub use embedded_graphics;
+#[cfg(feature = "half")]
+#[doc(hidden)]
+pub use half;
+#[cfg(feature = "zerocopy")]
+#[doc(hidden)]
+pub use zerocopy;
+
+#[cfg(feature = "ndarray")]
+mod array;
+mod bits;
+mod columns;
+mod concat;
+mod const_panic;
+mod endian;
+#[cfg(feature = "fallback")]
+mod fallback;
+mod fingerprint;
+mod fixed;
+#[cfg(feature = "half")]
+mod float16;
+#[cfg(feature = "zerocopy")]
+mod from_bytes;
+#[cfg(feature = "glob")]
+mod glob;
+mod header;
+#[cfg(feature = "hot-reload")]
+mod hot;
+#[cfg(feature = "embedded-graphics")]
+mod image;
+#[cfg(any(feature = "glam", feature = "mint"))]
+mod linalg;
+mod lines;
+mod map;
+#[cfg(feature = "mmap")]
+mod mmap;
+mod packed;
+mod records;
+mod shaped;
+mod sorted;
+mod typed;
+
+#[cfg(feature = "ndarray")]
+#[doc(hidden)]
+pub use array::{npy_data_start, npy_ndim};
+#[cfg(feature = "ndarray")]
+pub use array::{NpyElement, StaticArray};
+#[doc(hidden)]
+pub use bits::BitOptions;
+pub use bits::{BitIter, BitOrder, BitSlice};
+#[doc(hidden)]
+pub use concat::{concat_bounds, concat_bytes, concat_len};
+#[doc(hidden)]
+pub use endian::to_native_bytes;
+pub use endian::Endian;
+#[doc(hidden)]
+pub use fingerprint::FINGERPRINT_HEADER_LEN;
+pub use fingerprint::{FingerprintHasher, LayoutFingerprint};
+#[doc(hidden)]
+pub use fixed::{decimal_count, decimal_fixed};
+pub use fixed::{FixedPoint, Q15, Q31, Q7};
+#[cfg(feature = "zerocopy")]
+#[doc(hidden)]
+pub use from_bytes::ZerocopyAligned;
+#[cfg(feature = "hot-reload")]
+#[doc(hidden)]
+pub use hot::read_included;
+#[cfg(feature = "hot-reload")]
+pub use hot::{HotData, HotSlice, Snapshot};
+#[cfg(feature = "embedded-graphics")]
+pub use image::NetpbmColor;
+#[cfg(feature = "embedded-graphics")]
+#[doc(hidden)]
+pub use image::{
+    decode_netpbm, image_width, netpbm_len, netpbm_width, ImageByteOrder, PixelFormat,
+};
+#[cfg(feature = "hot-reload")]
+#[doc(hidden)]
+pub use include_data_macros::current_dir_impl as __current_dir_impl;
+#[cfg(feature = "fallback")]
+#[doc(hidden)]
+pub use include_data_macros::if_file_exists_impl as __if_file_exists_impl;
+#[cfg(feature = "glob")]
+#[doc(hidden)]
+pub use include_data_macros::include_glob_impl as __include_glob_impl;
+#[doc(hidden)]
+pub use include_data_macros::workspace_dir_impl as __workspace_dir_impl;
+#[cfg(feature = "mint")]
+pub use linalg::MintLayout;
+#[cfg(feature = "glam")]
+pub use linalg::Padded;
+#[cfg(feature = "glam")]
+#[doc(hidden)]
+pub use linalg::{pad_groups, padded_len};
+#[doc(hidden)]
+pub use lines::{line_count, lines, LineOptions};
+pub use map::StaticMap;
+#[doc(hidden)]
+pub use map::{map_len, map_order, map_permute};
+#[cfg(feature = "mmap")]
+pub use mmap::{IncludedSlice, MmapError};
+#[doc(hidden)]
+pub use packed::PackedOptions;
+pub use packed::{PackedIter, PackedSlice, PackedValue};
+#[doc(hidden)]
+pub use records::record_offsets;
+pub use records::{RecordFormat, Records, RecordsIter};
+#[doc(hidden)]
+pub use sorted::{check_sorted, OrdKind, OrdLayout};
+pub use sorted::{ConstOrd, SortedSlice};
+pub use typed::{typed_checksum, write_typed, TypedHeader, TypedHeaderError};
+
+/// Derive [`bytemuck::Zeroable`] and [`bytemuck::Pod`] (and therefore
+/// [`bytemuck::AnyBitPattern`]) for a type, after checking at compile time
+/// that doing so is sound.
+///
+/// This removes the need for `unsafe impl`s, and for manually checking that
+/// custom types satisfy the requirements of [`bytemuck::AnyBitPattern`],
+/// before using them with the macros in this crate. The derive can be used on
+/// `struct`s which:
+/// - are `#[repr(C)]` or `#[repr(transparent)]` (other `repr` arguments such as
+///   `packed` or `align` may also be given)
+/// - have only fields which are themselves [`bytemuck::Pod`], which includes
+///   primitive numbers, arrays, and other types deriving `IncludeData`
+/// - contain no padding bytes
+/// - are `Copy` and have no generic parameters
+///
+/// Any violation is a compiler error.
+///
+/// The generated code refers to `::include_data`, so `include_data` must be a
+/// direct dependency of the crate using the derive.
+///
+/// Requires the `derive` feature.
+///
+/// # Example
+///
+/// ```
+/// # use include_data::{include_data, IncludeData};
+/// #[repr(C)]
+/// #[derive(Copy, Clone, IncludeData)]
+/// struct Foo {
+///     integer: u16,
+///     pair: [u8; 2],
+/// }
+///
+/// static FOO_DATA: Foo = include_data!("../tests/test_data/file_exactly_4_bytes_long");
+/// ```
+///
+/// Types with padding are rejected:
+///
+/// ```compile_fail
+/// # use include_data::IncludeData;
+/// #[repr(C)]
+/// #[derive(Copy, Clone, IncludeData)]
+/// struct Padded {
+///     byte: u8,
+///     two_bytes: u16,
+/// }
+/// ```
+#[cfg(feature = "derive")]
+pub use include_data_derive::IncludeData;
+
+/// Derive [`LayoutFingerprint`] for a struct.
+///
+/// The fingerprint is computed from the struct's size and alignment, and the
+/// offset and [`LayoutFingerprint`] of every field, so every field type must
+/// also implement [`LayoutFingerprint`]. Field names and the name of the
+/// struct itself do not affect the fingerprint.
+///
+/// Generic structs are not supported, since field offsets cannot yet be
+/// computed for generic types at compile time.
+///
+/// Requires the `derive` feature.
+///
+/// # Example
+///
+/// ```
+/// # use include_data::LayoutFingerprint;
+/// #[repr(C)]
+/// #[derive(LayoutFingerprint)]
+/// struct StructWithBool {
+///     boolean: bool,
+///     two_bytes: u16,
+/// }
+///
+/// #[derive(LayoutFingerprint)]
+/// struct Reordered {
+///     two_bytes: u16,
+///     boolean: bool,
+/// }
+///
+/// assert_ne!(StructWithBool::FINGERPRINT, Reordered::FINGERPRINT);
+/// ```
+#[cfg(feature = "derive")]
+pub use include_data_derive::LayoutFingerprint;
+
+/// [`include_data`] with `via = zerocopy`, which requires the `zerocopy`
+/// feature.
+#[cfg(not(feature = "zerocopy"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __include_data_zerocopy {
+    ($($args:tt)*) => {
+        ::core::compile_error!("`via = zerocopy` requires the `zerocopy` feature of `include_data`")
+    };
+}
+
+/// [`include_slice`] with `via = zerocopy`, which requires the `zerocopy`
+/// feature.
+#[cfg(not(feature = "zerocopy"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __include_slice_zerocopy {
+    ($($args:tt)*) => {
+        ::core::compile_error!("`via = zerocopy` requires the `zerocopy` feature of `include_data`")
+    };
+}
+
+/// [`include_data`] with `hot`, which requires the `hot-reload` feature.
+#[cfg(not(feature = "hot-reload"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __include_data_hot {
+    ($($args:tt)*) => {
+        ::core::compile_error!("`hot` requires the `hot-reload` feature of `include_data`")
+    };
+}
+
+/// [`include_slice`] with `hot`, which requires the `hot-reload` feature.
+#[cfg(not(feature = "hot-reload"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __include_slice_hot {
+    ($($args:tt)*) => {
+        ::core::compile_error!("`hot` requires the `hot-reload` feature of `include_data`")
+    };
+}
+
+/// Used by [`include_data`] and [`include_slice`] to reject unknown values of
+/// `via`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __unknown_via {
+    ($via:tt) => {
+        ::core::compile_error!(::core::concat!(
+            "Unknown option `via = ",
+            ::core::stringify!($via),
+            "`, expected `via = bytemuck` or `via = zerocopy`",
+        ))
+    };
+}
 
 /// Include data from a file as static data in the executable, of a type that
 /// implements [`bytemuck::AnyBitPattern`].
@@ -93,14 +427,84 @@ pub use bytemuck;
 /// static FOUR_BYTES: [u8; 4] = include_data!("../tests/test_data/file_exactly_4_bytes_long");
 /// ```
 ///
+/// # Path roots
+///
+/// By default, paths are relative to the file containing the macro call, as
+/// for [`core::include_bytes`]. Alternatively, a path can be resolved relative
+/// to another root directory by adding a `from = ...` argument:
+///
+/// - `from = manifest` - the directory containing the crate's `Cargo.toml`
+///   (`CARGO_MANIFEST_DIR`)
+/// - `from = out_dir` - the build script output directory (`OUT_DIR`), for
+///   data generated by a build script
+/// - `from = workspace` - the root of the crate's workspace, found by searching
+///   upwards from the manifest directory for a `Cargo.toml` with a
+///   `[workspace]` table. This can be overridden by setting the
+///   `CARGO_WORKSPACE_DIR` environment variable
+/// - `from = env("VAR")` - the directory in any environment variable
+///
+/// The same option is supported by [`include_slice`] and [`include_unsafe`].
+/// Roots are resolved at compile time and, as with [`core::env`], the crate is
+/// rebuilt if the environment variable changes (including
+/// `CARGO_WORKSPACE_DIR`). Changes to the `[workspace]` tables which were
+/// searched only trigger a rebuild with the `nightly` feature.
+///
+/// ```
+/// # use include_data::include_data;
+/// const MY_INTEGER: i32 = include_data!("tests/test_data/file_exactly_4_bytes_long", from = manifest);
+/// ```
+///
+/// # `zerocopy` types
+///
+/// With the `zerocopy` feature, adding a `via = zerocopy` argument (after any
+/// `from = ...` argument) instead requires the target type to implement
+/// `zerocopy::FromBytes`, so types which derive the `zerocopy` traits can be
+/// included without also implementing the `bytemuck` traits. `via = bytemuck`
+/// is the default.
+///
+/// ```
+/// # use include_data::include_data;
+/// use zerocopy::FromBytes;
+///
+/// #[derive(FromBytes)]
+/// #[repr(C)]
+/// struct Header {
+///     magic: u16,
+///     version: u16,
+/// }
+///
+/// static HEADER: Header = include_data!("../tests/test_data/file_exactly_4_bytes_long", via = zerocopy);
+/// ```
+///
+/// # Hot reloading
+///
+/// With the `hot-reload` feature, adding a `hot` argument (after any
+/// `from = ...` argument) evaluates to a [`HotData`] handle instead, which must
+/// be assigned to a `static`. In builds with `debug_assertions`, the handle
+/// reads the file from disk at runtime and reloads it when it changes, so data
+/// can be tuned without recompiling. Release builds use the data embedded at
+/// compile time, as without `hot`.
+///
+/// A `static` of the data itself cannot change at runtime, so the type of the
+/// `static` changes to the handle, and code using the data takes a
+/// [`Snapshot`] of it with [`HotData::current`]:
+///
+/// ```
+/// # use include_data::{include_data, HotData};
+/// static GAIN: HotData<f32> = include_data!("../tests/test_data/binary_4", hot);
+///
+/// let doubled = *GAIN.current() * 2.0;
+/// ```
+///
 /// # Safety
 ///
 /// This macro is safe. However, if used on a custom type, that type must
-/// implement [`bytemuck::AnyBitPattern`]. Implementing that trait has very
-/// strict safety requirements which must be observed.
+/// implement [`bytemuck::AnyBitPattern`] (or `zerocopy::FromBytes` with
+/// `via = zerocopy`). Implementing those traits by hand has very strict safety
+/// requirements which must be observed.
 #[macro_export]
 macro_rules! include_data {
-    ($file:expr) => {{
+    ($file:expr $(,)?) => {{
         const fn typecheck<T: $crate::bytemuck::AnyBitPattern>(src: T) -> T {
             src
         }
@@ -110,6 +514,24 @@ macro_rules! include_data {
         // guaranteed by `transmute`.
         typecheck(unsafe { ::core::mem::transmute(*::core::include_bytes!($file)) })
     }};
+    ($file:expr, via = bytemuck $(,)?) => {
+        $crate::include_data!($file)
+    };
+    ($file:expr, via = zerocopy $(,)?) => {
+        $crate::__include_data_zerocopy!($file)
+    };
+    ($file:expr, via = $via:tt $(,)?) => {
+        $crate::__unknown_via!($via)
+    };
+    ($file:expr, hot $(,)?) => {
+        $crate::__include_data_hot!($file)
+    };
+    ($file:expr, from = $root:ident $(($var:literal))?, hot $(,)?) => {
+        $crate::__include_data_hot!($file, from = $root $(($var))?)
+    };
+    ($file:expr, from = $root:ident $(($var:literal))? $(, via = $via:tt)? $(,)?) => {
+        $crate::include_data!($crate::__resolve_path!($root $(($var))?, $file) $(, via = $via)?)
+    };
 }
 
 /// Include data from a file as static data in the executable, without checking
@@ -173,12 +595,117 @@ macro_rules! include_data {
 ///
 /// Maintaining soundness when using this macro is delicate. In particular,
 /// changing the contents of the source file or the definition of the target
-/// type at all will often silently result in undefined behaviour.
+/// type at all will often silently result in undefined behaviour. Checking
+/// layout fingerprints (see below) mitigates some, but not all, of this risk.
+///
+/// # Layout fingerprints
+///
+/// If the target type implements [`LayoutFingerprint`], the layout it was
+/// compiled with can be checked against an expected fingerprint, turning some
+/// silent layout changes into compiler errors. The expected fingerprint can
+/// either be passed as an argument:
+///
+/// ```
+/// # use include_data::{include_unsafe, LayoutFingerprint};
+/// # #[repr(C)]
+/// # struct StructWithBool {
+/// #     boolean: bool,
+/// #     two_bytes: u16,
+/// # }
+/// # impl LayoutFingerprint for StructWithBool {
+/// #     const FINGERPRINT: u64 = 0x1234;
+/// # }
+/// static BAR_DATA: StructWithBool = unsafe {
+///     include_unsafe!("../tests/test_data/file_exactly_4_bytes_long", fingerprint = 0x1234)
+/// };
+/// ```
+///
+/// or stored in the first 8 bytes of the file (as a little-endian `u64`),
+/// followed by the data itself:
+///
+/// ```
+/// # use include_data::{include_unsafe, LayoutFingerprint};
+/// # #[repr(C)]
+/// # struct StructWithBool {
+/// #     boolean: bool,
+/// #     two_bytes: u16,
+/// # }
+/// # impl LayoutFingerprint for StructWithBool {
+/// #     const FINGERPRINT: u64 = 0xea64_e56e_a2a1_dc3b;
+/// # }
+/// static BAR_DATA: StructWithBool = unsafe {
+///     include_unsafe!("../tests/test_data/fingerprinted_struct_with_bool", fingerprint_header)
+/// };
+/// # assert_eq!(BAR_DATA.boolean, true);
+/// ```
+///
+/// In either case, a compiler error will be thrown if the fingerprints do not
+/// match, including when the macro is used in `let` position. (There, the
+/// check runs when the function is compiled to machine code, so it is
+/// reported by `cargo build` but not by `cargo check`.) A file with a header
+/// can be written by writing `T::FINGERPRINT.to_le_bytes()` followed by the
+/// data.
+///
+/// # Path roots
+///
+/// As with [`include_data`](crate::include_data#path-roots), paths can be
+/// resolved relative to another root directory by adding a `from = ...`
+/// argument directly after the path, e.g.
+/// `include_unsafe!("data", from = out_dir, fingerprint_header)`.
 #[macro_export]
 macro_rules! include_unsafe {
-    ($file:expr) => {{
+    ($file:expr $(,)?) => {{
         ::core::convert::identity(::core::mem::transmute(*::core::include_bytes!($file)))
     }};
+    ($file:expr, fingerprint = $fingerprint:expr $(,)?) => {{
+        $crate::__check_fingerprint!(
+            ::core::mem::transmute(*::core::include_bytes!($file)),
+            $fingerprint,
+        )
+    }};
+    ($file:expr, fingerprint_header $(,)?) => {{
+        const BYTES: &[u8] = ::core::include_bytes!($file);
+        const PAYLOAD_LEN: usize = {
+            assert!(
+                BYTES.len() >= $crate::FINGERPRINT_HEADER_LEN,
+                "Included file is too short to contain a layout fingerprint header",
+            );
+            BYTES.len() - $crate::FINGERPRINT_HEADER_LEN
+        };
+        const PAYLOAD: [u8; PAYLOAD_LEN] =
+            $crate::copy_bytes(BYTES, $crate::FINGERPRINT_HEADER_LEN);
+        const FINGERPRINT: u64 = u64::from_le_bytes($crate::copy_bytes(BYTES, 0));
+
+        $crate::__check_fingerprint!(::core::mem::transmute(PAYLOAD), FINGERPRINT)
+    }};
+    ($file:expr, from = $root:ident $(($var:literal))? $(, $($rest:tt)*)?) => {
+        $crate::include_unsafe!($crate::__resolve_path!($root $(($var))?, $file) $(, $($rest)*)?)
+    };
+}
+
+/// Used by [`include_unsafe`] to compare layout fingerprints.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __check_fingerprint {
+    ($value:expr, $fingerprint:expr $(,)?) => {{
+        struct Check<T, const EXPECTED: u64>(::core::marker::PhantomData<T>);
+
+        impl<T: $crate::LayoutFingerprint, const EXPECTED: u64> Check<T, EXPECTED> {
+            // An associated const is evaluated at compile time wherever it is
+            // used, so a mismatch is a compiler error even in `let` position.
+            const OK: () = assert!(
+                T::FINGERPRINT == EXPECTED,
+                "Layout fingerprint of target type does not match the expected fingerprint",
+            );
+        }
+
+        const fn check_fingerprint<T: $crate::LayoutFingerprint, const EXPECTED: u64>(src: T) -> T {
+            let () = Check::<T, EXPECTED>::OK;
+            src
+        }
+
+        check_fingerprint::<_, { $fingerprint }>($value)
+    }};
 }
 
 /// Include data from a file as static data, consisting of a slice of
@@ -211,10 +738,51 @@ macro_rules! include_unsafe {
 /// static DATA_U32: &[u32] = include_slice!(u32, "../tests/test_data/binary_32");
 /// ```
 ///
+/// Paths can also be resolved relative to another root directory, such as
+/// `OUT_DIR`, with a `from = ...` argument. See
+/// [`include_data`](crate::include_data#path-roots) for details.
+///
+/// ```rust
+/// # use include_data::include_slice;
+/// static DATA_U32: &[u32] = include_slice!(u32, "tests/test_data/binary_32", from = manifest);
+/// ```
+///
+/// With the `zerocopy` feature, `include_slice!(T, path, via = zerocopy)`
+/// instead requires `T` to implement `zerocopy::FromBytes` and
+/// `zerocopy::Immutable`, as for
+/// [`include_data`](crate::include_data#zerocopy-types).
+///
+/// ```rust
+/// # use include_data::include_slice;
+/// use zerocopy::{FromBytes, Immutable};
+///
+/// #[derive(FromBytes, Immutable)]
+/// #[repr(C)]
+/// struct Sample {
+///     left: i16,
+///     right: i16,
+/// }
+///
+/// static SAMPLES: &[Sample] =
+///     include_slice!(Sample, "tests/test_data/binary_32", from = manifest, via = zerocopy);
+/// ```
+///
+/// With the `hot-reload` feature, `include_slice!(T, path, hot)` instead
+/// evaluates to a [`HotSlice`] handle, which reloads the file at runtime when
+/// it changes, as for [`include_data`](crate::include_data#hot-reloading).
+///
+/// ```rust
+/// # use include_data::{include_slice, HotSlice};
+/// static TABLE: HotSlice<u32> = include_slice!(u32, "../tests/test_data/binary_32", hot);
+///
+/// let total: u32 = TABLE.current().iter().sum();
+/// ```
+///
 /// # Safety
 ///
 /// This macro is safe. However, if used on a custom type, that type must
-/// implement [`bytemuck::AnyBitPattern`]. Implementing that trait has very struct safety
+/// implement [`bytemuck::AnyBitPattern`] (or the `zerocopy` traits with
+/// `via = zerocopy`). Implementing those traits by hand has very strict safety
 /// requirements which must be observed.
 #[macro_export]
 macro_rules! include_slice {
@@ -239,6 +807,93 @@ macro_rules! include_slice {
 
         out_slice
     }};
+    ($target_ty:ty, $file:expr, via = bytemuck $(,)?) => {
+        $crate::include_slice!($target_ty, $file)
+    };
+    ($target_ty:ty, $file:expr, via = zerocopy $(,)?) => {
+        $crate::__include_slice_zerocopy!($target_ty, $file)
+    };
+    ($target_ty:ty, $file:expr, via = $via:tt $(,)?) => {
+        $crate::__unknown_via!($via)
+    };
+    ($target_ty:ty, $file:expr, hot $(,)?) => {
+        $crate::__include_slice_hot!($target_ty, $file)
+    };
+    ($target_ty:ty, $file:expr, from = $root:ident $(($var:literal))?, hot $(,)?) => {
+        $crate::__include_slice_hot!($target_ty, $file, from = $root $(($var))?)
+    };
+    ($target_ty:ty, $file:expr, from = $root:ident $(($var:literal))? $(, via = $via:tt)? $(,)?) => {
+        $crate::include_slice!(
+            $target_ty,
+            $crate::__resolve_path!($root $(($var))?, $file)
+            $(, via = $via)?
+        )
+    };
+}
+
+/// As [`include_slice`], but usable in `const` contexts.
+///
+/// Used by macros which need to refer to included slices from `const fn`s.
+/// Unlike [`include_slice`], every use of the resulting constant may
+/// duplicate the data, so that macro should be preferred wherever possible.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __include_slice_const {
+    ($target_ty:ty, $file:expr $(,)?) => {{
+        const SIZE: usize = ::core::mem::size_of::<$target_ty>();
+
+        const ALIGNED: &$crate::AlignedAs<$target_ty, [u8]> = &$crate::AlignedAs {
+            _align: [],
+            bytes: *::core::include_bytes!($file),
+        };
+
+        const OUT_SLICE: &[$target_ty] = {
+            let byte_slice: &[u8] = &ALIGNED.bytes;
+
+            assert!(
+                byte_slice.len() % SIZE == 0,
+                "Included file size is not divisible by target type size",
+            );
+
+            unsafe {
+                ::core::slice::from_raw_parts(byte_slice.as_ptr().cast(), byte_slice.len() / SIZE)
+            }
+        };
+
+        OUT_SLICE
+    }};
+}
+
+/// As [`include_slice`], but for a file of primitive values stored with the
+/// given [`Endian`], which are converted to native byte order at compile time.
+///
+/// Used by the endianness-aware aliases. Every value is byte-swapped as a
+/// whole, so this is only correct for primitive types.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __include_slice_endian {
+    ($target_ty:ty, $endian:expr, $file:expr $(,)?) => {{
+        const SIZE: usize = ::core::mem::size_of::<$target_ty>();
+        const BYTES: &[u8] = ::core::include_bytes!($file);
+
+        const ALIGNED: &$crate::AlignedAs<$target_ty, [u8]> = &$crate::AlignedAs {
+            _align: [],
+            bytes: $crate::to_native_bytes::<{ BYTES.len() }>(BYTES, SIZE, $endian),
+        };
+
+        const OUT_SLICE: &[$target_ty] = unsafe {
+            ::core::slice::from_raw_parts(ALIGNED.bytes.as_ptr().cast(), ALIGNED.bytes.len() / SIZE)
+        };
+
+        OUT_SLICE
+    }};
+    ($target_ty:ty, $endian:expr, $file:expr, from = $root:ident $(($var:literal))? $(,)?) => {
+        $crate::__include_slice_endian!(
+            $target_ty,
+            $endian,
+            $crate::__resolve_path!($root $(($var))?, $file),
+        )
+    };
 }
 
 /// Alias of [`include_slice(u8, path)`](include_slice). Returns a `&'static [u8]`.
@@ -247,117 +902,151 @@ macro_rules! include_slice {
 /// should be prefered as it is a compiler built-in.
 #[macro_export]
 macro_rules! include_u8s {
-    ($file:expr $(,)?) => {
-        $crate::include_slice!(u8, $file)
+    ($($args:tt)*) => {
+        $crate::include_slice!(u8, $($args)*)
     };
 }
 
 /// Alias of [`include_slice(u16, path)`](include_slice). Returns a `&'static [u16]`.
 #[macro_export]
 macro_rules! include_u16s {
-    ($file:expr $(,)?) => {
-        $crate::include_slice!(u16, $file)
+    ($($args:tt)*) => {
+        $crate::include_slice!(u16, $($args)*)
     };
 }
 
 /// Alias of [`include_slice(u32, path)`](include_slice). Returns a `&'static [u32]`.
 #[macro_export]
 macro_rules! include_u32s {
-    ($file:expr $(,)?) => {
-        $crate::include_slice!(u32, $file)
+    ($($args:tt)*) => {
+        $crate::include_slice!(u32, $($args)*)
     };
 }
 
 /// Alias of [`include_slice(u64, path)`](include_slice). Returns a `&'static [u64]`.
 #[macro_export]
 macro_rules! include_u64s {
-    ($file:expr $(,)?) => {
-        $crate::include_slice!(u64, $file)
+    ($($args:tt)*) => {
+        $crate::include_slice!(u64, $($args)*)
     };
 }
 
 /// Alias of [`include_slice(u128, path)`](include_slice). Returns a `&'static [u128]`.
 #[macro_export]
 macro_rules! include_u128s {
-    ($file:expr $(,)?) => {
-        $crate::include_slice!(u128, $file)
+    ($($args:tt)*) => {
+        $crate::include_slice!(u128, $($args)*)
     };
 }
 
 /// Alias of [`include_slice(usize, path)`](include_slice). Returns a `&'static [usize]`.
 #[macro_export]
 macro_rules! include_usizes {
-    ($file:expr $(,)?) => {
-        $crate::include_slice!(usize, $file)
+    ($($args:tt)*) => {
+        $crate::include_slice!(usize, $($args)*)
     };
 }
 
 /// Alias of [`include_slice(i8, path)`](include_slice). Returns a `&'static [i8]`.
 #[macro_export]
 macro_rules! include_i8s {
-    ($file:expr $(,)?) => {
-        $crate::include_slice!(i8, $file)
+    ($($args:tt)*) => {
+        $crate::include_slice!(i8, $($args)*)
     };
 }
 
 /// Alias of [`include_slice(i16, path)`](include_slice). Returns a `&'static [i16]`.
 #[macro_export]
 macro_rules! include_i16s {
-    ($file:expr $(,)?) => {
-        $crate::include_slice!(i16, $file)
+    ($($args:tt)*) => {
+        $crate::include_slice!(i16, $($args)*)
     };
 }
 
 /// Alias of [`include_slice(i32, path)`](include_slice). Returns a `&'static [i32]`.
 #[macro_export]
 macro_rules! include_i32s {
-    ($file:expr $(,)?) => {
-        $crate::include_slice!(i32, $file)
+    ($($args:tt)*) => {
+        $crate::include_slice!(i32, $($args)*)
     };
 }
 
 /// Alias of [`include_slice(i64, path)`](include_slice). Returns a `&'static [i64]`.
 #[macro_export]
 macro_rules! include_i64s {
-    ($file:expr $(,)?) => {
-        $crate::include_slice!(i64, $file)
+    ($($args:tt)*) => {
+        $crate::include_slice!(i64, $($args)*)
     };
 }
 
 /// Alias of [`include_slice(i128, path)`](include_slice). Returns a `&'static [i128]`.
 #[macro_export]
 macro_rules! include_i128s {
-    ($file:expr $(,)?) => {
-        $crate::include_slice!(i128, $file)
+    ($($args:tt)*) => {
+        $crate::include_slice!(i128, $($args)*)
     };
 }
 
 /// Alias of [`include_slice(isize, path)`](include_slice). Returns a `&'static [isize]`.
 #[macro_export]
 macro_rules! include_isizes {
-    ($file:expr $(,)?) => {
-        $crate::include_slice!(isize, $file)
+    ($($args:tt)*) => {
+        $crate::include_slice!(isize, $($args)*)
     };
 }
 
 /// Alias of [`include_slice(f32, path)`](include_slice). Returns a `&'static [f32]`.
 #[macro_export]
 macro_rules! include_f32s {
-    ($file:expr $(,)?) => {
-        $crate::include_slice!(f32, $file)
+    ($($args:tt)*) => {
+        $crate::include_slice!(f32, $($args)*)
     };
 }
 
 /// Alias of [`include_slice(f64, path)`](include_slice). Returns a `&'static [f64]`.
 #[macro_export]
 macro_rules! include_f64s {
-    ($file:expr $(,)?) => {
-        $crate::include_slice!(f64, $file)
+    ($($args:tt)*) => {
+        $crate::include_slice!(f64, $($args)*)
+    };
+}
+
+/// Resolve a path relative to one of the roots supported by the `from = ...`
+/// option of the include macros.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __resolve_path {
+    (manifest, $file:expr) => {
+        ::core::concat!(::core::env!("CARGO_MANIFEST_DIR"), "/", $file)
+    };
+    (out_dir, $file:expr) => {
+        ::core::concat!(
+            ::core::env!(
+                "OUT_DIR",
+                "`OUT_DIR` is not set: `from = out_dir` requires the crate to have a build script",
+            ),
+            "/",
+            $file,
+        )
+    };
+    (workspace, $file:expr) => {
+        ::core::concat!($crate::__workspace_dir_impl!(), "/", $file)
+    };
+    (env($var:literal), $file:expr) => {
+        ::core::concat!(::core::env!($var), "/", $file)
+    };
+    ($root:ident $(($var:literal))?, $file:expr) => {
+        ::core::compile_error!(::core::concat!(
+            "Unknown path root `",
+            ::core::stringify!($root),
+            "`, expected one of `manifest`, `out_dir`, `workspace` or `env(\"VAR\")`",
+        ))
     };
 }
 
 /// Force alignment of the `bytes` member to match that of type T.
-/// `B` is simply `[u8]` but handles that it is unsized.
+/// `B` is simply `[u8]` but handles that it is unsized. Nesting
+/// `AlignedAs<H, AlignedAs<T, [u8]>>` aligns for both `H` and `T`.
 #[doc(hidden)]
 #[repr(C)]
 pub struct AlignedAs<T: bytemuck::AnyBitPattern, B: Bytes + ?Sized> {
@@ -371,3 +1060,26 @@ pub trait Bytes {}
 impl Bytes for [u8] {}
 
 impl<const N: usize> Bytes for [u8; N] {}
+
+impl<T: bytemuck::AnyBitPattern, B: Bytes + ?Sized> Bytes for AlignedAs<T, B> {}
+
+/// Copy `N` bytes, starting at `offset`, into an array.
+///
+/// Used in `const` contexts to split sized sections (such as headers) out of
+/// included files.
+#[doc(hidden)]
+#[must_use]
+pub const fn copy_bytes<const N: usize>(bytes: &[u8], offset: usize) -> [u8; N] {
+    assert!(
+        offset + N <= bytes.len(),
+        "Included file is too short for the requested data",
+    );
+
+    let mut out = [0; N];
+    let mut i = 0;
+    while i < N {
+        out[i] = bytes[offset + i];
+        i += 1;
+    }
+    out
+}
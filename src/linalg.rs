@@ -0,0 +1,223 @@
+/// `glam` types which contain SIMD padding, which can be included from packed
+/// data with [`include_padded`](crate::include_padded).
+///
+/// Each of these types is made of groups of three `f32`s, each padded to 16
+/// bytes: [`glam::Vec3A`] is one group, [`glam::Mat3A`] three and
+/// [`glam::Affine3A`] four.
+#[cfg(feature = "glam")]
+pub trait Padded: bytemuck::AnyBitPattern {
+    #[doc(hidden)]
+    const GROUPS: usize;
+}
+
+#[cfg(feature = "glam")]
+impl Padded for glam::Vec3A {
+    const GROUPS: usize = 1;
+}
+
+#[cfg(feature = "glam")]
+impl Padded for glam::Mat3A {
+    const GROUPS: usize = 3;
+}
+
+#[cfg(feature = "glam")]
+impl Padded for glam::Affine3A {
+    const GROUPS: usize = 4;
+}
+
+/// The number of values of a [`Padded`] type with `groups` groups of three
+/// `f32`s in the packed `bytes`.
+#[cfg(feature = "glam")]
+#[doc(hidden)]
+#[must_use]
+pub const fn padded_len(bytes: &[u8], groups: usize) -> usize {
+    assert!(
+        bytes.len() % (groups * 12) == 0,
+        "Included file size is not divisible by packed type size",
+    );
+    bytes.len() / (groups * 12)
+}
+
+/// The packed groups of three `f32`s in `bytes`, each padded with zeroes to 16
+/// bytes.
+#[cfg(feature = "glam")]
+#[doc(hidden)]
+#[must_use]
+pub const fn pad_groups<const N: usize>(bytes: &[u8]) -> [u8; N] {
+    assert!(
+        bytes.len() % 12 == 0 && bytes.len() / 12 * 16 == N,
+        "Wrong length of padded data",
+    );
+    let mut out = [0; N];
+    let mut i = 0;
+    while i < bytes.len() {
+        out[i / 12 * 16 + i % 12] = bytes[i];
+        i += 1;
+    }
+    out
+}
+
+/// Types of the `mint` crate, which can be included with
+/// [`include_mint`](crate::include_mint).
+///
+/// Implemented for all `mint` vectors, points, matrices and rotations whose
+/// scalar type implements [`bytemuck::AnyBitPattern`].
+///
+/// # Safety
+///
+/// The type must consist only of values of `Scalar`, with no padding, and have
+/// the same alignment as `Scalar`. This holds for all `mint` types, which are
+/// `#[repr(C)]` structs of their scalar type.
+#[cfg(feature = "mint")]
+pub unsafe trait MintLayout {
+    /// The type of each component.
+    type Scalar: bytemuck::AnyBitPattern;
+}
+
+#[cfg(feature = "mint")]
+macro_rules! impl_mint_layout {
+    ($($ty:ident),*) => {
+        $(
+            unsafe impl<T: bytemuck::AnyBitPattern> MintLayout for mint::$ty<T> {
+                type Scalar = T;
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "mint")]
+impl_mint_layout!(
+    Vector2,
+    Vector3,
+    Vector4,
+    Point2,
+    Point3,
+    Quaternion,
+    RowMatrix2,
+    RowMatrix2x3,
+    RowMatrix2x4,
+    RowMatrix3x2,
+    RowMatrix3,
+    RowMatrix3x4,
+    RowMatrix4x2,
+    RowMatrix4x3,
+    RowMatrix4,
+    ColumnMatrix2,
+    ColumnMatrix2x3,
+    ColumnMatrix2x4,
+    ColumnMatrix3x2,
+    ColumnMatrix3,
+    ColumnMatrix3x4,
+    ColumnMatrix4x2,
+    ColumnMatrix4x3,
+    ColumnMatrix4
+);
+
+#[cfg(feature = "mint")]
+unsafe impl<T: bytemuck::AnyBitPattern, B> MintLayout for mint::EulerAngles<T, B> {
+    type Scalar = T;
+}
+
+/// Include a file of packed `f32` data as a slice of a `glam` type with SIMD
+/// padding, such as [`glam::Vec3A`].
+///
+/// Types such as `Vec3A` are 16 bytes long, with the last 4 bytes of each
+/// group of three `f32`s unused, so they can be included with
+/// [`include_slice`](crate::include_slice) only from files with that padding.
+/// `include_padded!(T, path)` instead reads the file at `path` as packed groups
+/// of three `f32`s (as written for [`glam::Vec3`] or `[f32; 3]`) and pads each
+/// group at compile time. `T` must implement [`Padded`].
+///
+/// A compiler error will be thrown if the file size is not divisible by the
+/// packed size of `T`. This can assign to both `static` and `const`
+/// variables, and paths are interpreted by [`core::include_bytes`], or
+/// resolved with a `from = ...` argument as for
+/// [`include_data`](crate::include_data#path-roots).
+///
+/// # Example
+///
+/// ```
+/// # use include_data::include_padded;
+/// use glam::{Vec3, Vec3A};
+///
+/// static POINTS: &[Vec3A] = include_padded!(Vec3A, "../tests/test_data/vec3_f32");
+///
+/// assert_eq!(POINTS[1], Vec3A::new(-1.0, 0.5, 2.0));
+/// assert_eq!(Vec3::from(POINTS[1]), Vec3::new(-1.0, 0.5, 2.0));
+/// ```
+#[cfg(feature = "glam")]
+#[macro_export]
+macro_rules! include_padded {
+    ($target_ty:ty, $file:expr $(,)?) => {{
+        const SIZE: usize = ::core::mem::size_of::<$target_ty>();
+        const BYTES: &[u8] = ::core::include_bytes!($file);
+        const LEN: usize = $crate::padded_len(BYTES, <$target_ty as $crate::Padded>::GROUPS);
+
+        const ALIGNED: &$crate::AlignedAs<$target_ty, [u8]> = &$crate::AlignedAs {
+            _align: [],
+            bytes: $crate::pad_groups::<{ LEN * SIZE }>(BYTES),
+        };
+        const OUT_SLICE: &[$target_ty] =
+            unsafe { ::core::slice::from_raw_parts(ALIGNED.bytes.as_ptr().cast(), LEN) };
+
+        OUT_SLICE
+    }};
+    ($target_ty:ty, $file:expr, from = $root:ident $(($var:literal))? $(,)?) => {
+        $crate::include_padded!($target_ty, $crate::__resolve_path!($root $(($var))?, $file))
+    };
+}
+
+/// As [`include_slice`](crate::include_slice), but for the types of the
+/// `mint` crate, such as `mint::Vector3<f32>`.
+///
+/// `mint` types do not implement the `bytemuck` traits, so instead `T` must
+/// implement [`MintLayout`], which is implemented for all `mint` types whose
+/// scalar type implements [`bytemuck::AnyBitPattern`]. A compiler error will
+/// be thrown if the file size is not divisible by the size of `T`. This can
+/// assign to both `static` and `const` variables, and paths are interpreted by
+/// [`core::include_bytes`], or resolved with a `from = ...` argument as for
+/// [`include_data`](crate::include_data#path-roots).
+///
+/// # Example
+///
+/// ```
+/// # use include_data::include_mint;
+/// use mint::Vector3;
+///
+/// static NORMALS: &[Vector3<f32>] = include_mint!(Vector3<f32>, "../tests/test_data/vec3_f32");
+///
+/// assert_eq!(NORMALS[1], Vector3 { x: -1.0, y: 0.5, z: 2.0 });
+/// ```
+#[cfg(feature = "mint")]
+#[macro_export]
+macro_rules! include_mint {
+    ($target_ty:ty, $file:expr $(,)?) => {{
+        const SIZE: usize = ::core::mem::size_of::<$target_ty>();
+
+        const ALIGNED: &$crate::AlignedAs<<$target_ty as $crate::MintLayout>::Scalar, [u8]> =
+            &$crate::AlignedAs {
+                _align: [],
+                bytes: *::core::include_bytes!($file),
+            };
+
+        const OUT_SLICE: &[$target_ty] = {
+            let byte_slice: &[u8] = &ALIGNED.bytes;
+
+            assert!(
+                byte_slice.len() % SIZE == 0,
+                "Included file size is not divisible by target type size",
+            );
+
+            // Safety: `MintLayout` guarantees that the type is made only of
+            // its scalar type, with the same alignment.
+            unsafe {
+                ::core::slice::from_raw_parts(byte_slice.as_ptr().cast(), byte_slice.len() / SIZE)
+            }
+        };
+
+        OUT_SLICE
+    }};
+    ($target_ty:ty, $file:expr, from = $root:ident $(($var:literal))? $(,)?) => {
+        $crate::include_mint!($target_ty, $crate::__resolve_path!($root $(($var))?, $file))
+    };
+}
@@ -0,0 +1,189 @@
+/// Options for [`include_lines`](crate::include_lines), set by the macro.
+#[doc(hidden)]
+#[derive(Clone, Copy, Debug)]
+pub struct LineOptions {
+    pub trim: bool,
+    pub comment: Option<&'static str>,
+    pub count: Option<usize>,
+}
+
+impl LineOptions {
+    pub const DEFAULT: Self = Self {
+        trim: false,
+        comment: None,
+        count: None,
+    };
+}
+
+/// The number of lines kept from `text`, checking the expected count if any.
+#[doc(hidden)]
+#[must_use]
+pub const fn line_count(text: &str, options: LineOptions) -> usize {
+    if let Some(comment) = options.comment {
+        assert!(!comment.is_empty(), "Comment prefix must not be empty");
+    }
+
+    let bytes = text.as_bytes();
+    let mut count = 0;
+    let mut pos = 0;
+    while let Some((_, _, next)) = next_line(bytes, pos, options) {
+        count += 1;
+        pos = next;
+    }
+
+    if let Some(expected) = options.count {
+        assert!(
+            count == expected,
+            "Included file does not contain the expected number of lines",
+        );
+    }
+    count
+}
+
+/// The `N` lines kept from `text`.
+#[doc(hidden)]
+#[must_use]
+pub const fn lines<const N: usize>(text: &'static str, options: LineOptions) -> [&'static str; N] {
+    let bytes = text.as_bytes();
+    let mut out = [""; N];
+    let mut i = 0;
+    let mut pos = 0;
+    while let Some((start, end, next)) = next_line(bytes, pos, options) {
+        // Safety: `start` and `end` are both either the ends of `text` or
+        // next to an ASCII character, so are on `char` boundaries.
+        out[i] = unsafe {
+            core::str::from_utf8_unchecked(core::slice::from_raw_parts(
+                bytes.as_ptr().add(start),
+                end - start,
+            ))
+        };
+        i += 1;
+        pos = next;
+    }
+    assert!(i == N, "Wrong number of included lines");
+    out
+}
+
+/// The start and end of the next kept line at or after `pos`, and the start of
+/// the line after it.
+const fn next_line(
+    bytes: &[u8],
+    mut pos: usize,
+    options: LineOptions,
+) -> Option<(usize, usize, usize)> {
+    while pos < bytes.len() {
+        let mut start = pos;
+        let mut end = pos;
+        while end < bytes.len() && bytes[end] != b'\n' {
+            end += 1;
+        }
+        pos = end + 1;
+        if end > start && bytes[end - 1] == b'\r' {
+            end -= 1;
+        }
+
+        let mut content = start;
+        while content < end && bytes[content].is_ascii_whitespace() {
+            content += 1;
+        }
+        if let Some(comment) = options.comment {
+            if starts_with(bytes, content, comment.as_bytes()) {
+                continue;
+            }
+        }
+
+        if options.trim {
+            start = content;
+            while end > start && bytes[end - 1].is_ascii_whitespace() {
+                end -= 1;
+            }
+        }
+        return Some((start, end, pos));
+    }
+    None
+}
+
+/// Whether `bytes[at..]` starts with `prefix`.
+const fn starts_with(bytes: &[u8], at: usize, prefix: &[u8]) -> bool {
+    if bytes.len() - at < prefix.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < prefix.len() {
+        if bytes[at + i] != prefix[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Include a UTF-8 text file as a `&'static [&'static str]` slice with one
+/// element per line.
+///
+/// Lines are split as by [`str::lines`]: they end with `\n` or `\r\n`, which
+/// are not included, and a final empty line is ignored. The text is split at
+/// compile time, so strings can be looked up by index without any parsing at
+/// runtime. This can assign to both `static` and `const` variables, and paths
+/// are interpreted by [`core::include_str`].
+///
+/// Options may follow the path, in any order:
+/// - `trim` - remove leading and trailing ASCII whitespace from every line
+/// - `comments = "#"` - skip lines starting with the given prefix, ignoring
+///   leading ASCII whitespace
+/// - `count = N` - throw a compiler error unless exactly `N` lines are
+///   included (after skipping comments)
+///
+/// # Example
+///
+/// ```
+/// # use include_data::include_lines;
+/// static MESSAGES: &[&str] = include_lines!(
+///     "../tests/test_data/lines",
+///     trim,
+///     comments = "#",
+///     count = 3,
+/// );
+///
+/// assert_eq!(MESSAGES[0], "Hello");
+/// ```
+#[macro_export]
+#[allow(clippy::module_name_repetitions)]
+macro_rules! include_lines {
+    ($file:expr $(, $option:ident $(= $value:expr)?)* $(,)?) => {{
+        const TEXT: &str = ::core::include_str!($file);
+        const OPTIONS: $crate::LineOptions = {
+            #[allow(unused_mut)]
+            let mut options = $crate::LineOptions::DEFAULT;
+            $($crate::__line_option!(options, $option $(= $value)?);)*
+            options
+        };
+        const COUNT: usize = $crate::line_count(TEXT, OPTIONS);
+        const LINES: [&str; COUNT] = $crate::lines(TEXT, OPTIONS);
+        const OUT: &[&str] = &LINES;
+
+        OUT
+    }};
+}
+
+/// Set one option of [`include_lines`].
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __line_option {
+    ($options:ident, trim) => {
+        $options.trim = true
+    };
+    ($options:ident, comments = $comment:expr) => {
+        $options.comment = ::core::option::Option::Some($comment)
+    };
+    ($options:ident, count = $count:expr) => {
+        $options.count = ::core::option::Option::Some($count)
+    };
+    ($options:ident, $option:ident $(= $value:expr)?) => {
+        ::core::compile_error!(::core::concat!(
+            "Unknown option `",
+            ::core::stringify!($option),
+            "`, expected one of `trim`, `comments` or `count`",
+        ))
+    };
+}
@@ -0,0 +1,314 @@
+use core::cmp::Ordering;
+use core::fmt;
+
+/// A read-only map from keys of type `K` to values of type `V`, built at
+/// compile time with [`include_map`](crate::include_map).
+///
+/// Keys are stored sorted in an Eytzinger (breadth-first binary tree) layout,
+/// so [`StaticMap::get`] is a cache-friendly binary search without any
+/// hashing. Keys are compared by their bytes, so two keys are equal exactly
+/// when their bit patterns are: for example `0.0_f32` and `-0.0_f32` are
+/// different keys.
+#[allow(clippy::module_name_repetitions)]
+pub struct StaticMap<K: 'static, V: 'static> {
+    keys: &'static [K],
+    values: &'static [V],
+}
+
+impl<K, V> StaticMap<K, V> {
+    /// The number of entries.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Whether there are no entries.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// Iterate over the entries, in an unspecified order.
+    pub fn iter(
+        &self,
+    ) -> core::iter::Zip<core::slice::Iter<'static, K>, core::slice::Iter<'static, V>> {
+        self.keys.iter().zip(self.values)
+    }
+}
+
+impl<K: bytemuck::Pod, V: bytemuck::AnyBitPattern> StaticMap<K, V> {
+    /// Create a map from keys in Eytzinger order and their values.
+    #[doc(hidden)]
+    #[must_use]
+    pub const fn from_eytzinger(keys: &'static [K], values: &'static [V]) -> Self {
+        assert!(
+            keys.len() == values.len(),
+            "Map keys and values have different lengths",
+        );
+        Self { keys, values }
+    }
+
+    /// The value for `key`, if it is in the map.
+    #[must_use]
+    pub fn get(&self, key: &K) -> Option<&'static V> {
+        let target = bytemuck::bytes_of(key);
+        let mut node = 1;
+        while node <= self.keys.len() {
+            match bytemuck::bytes_of(&self.keys[node - 1]).cmp(target) {
+                Ordering::Equal => return Some(&self.values[node - 1]),
+                Ordering::Less => node = 2 * node + 1,
+                Ordering::Greater => node *= 2,
+            }
+        }
+        None
+    }
+
+    /// Whether `key` is in the map.
+    #[must_use]
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.get(key).is_some()
+    }
+}
+
+impl<K, V> Clone for StaticMap<K, V> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<K, V> Copy for StaticMap<K, V> {}
+
+impl<K: fmt::Debug, V: fmt::Debug> fmt::Debug for StaticMap<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+/// The number of records of `key_size + value_size` bytes in `bytes`.
+#[doc(hidden)]
+#[must_use]
+#[allow(clippy::module_name_repetitions)]
+pub const fn map_len(bytes: &[u8], key_size: usize, value_size: usize) -> usize {
+    assert!(
+        bytes.len() % (key_size + value_size) == 0,
+        "Included file size is not divisible by key and value size",
+    );
+    bytes.len() / (key_size + value_size)
+}
+
+/// The index of the record at each position of the Eytzinger layout of the
+/// `N` records in `bytes`, sorted by key.
+#[doc(hidden)]
+#[must_use]
+#[allow(clippy::module_name_repetitions)]
+pub const fn map_order<const N: usize>(
+    bytes: &[u8],
+    key_size: usize,
+    value_size: usize,
+) -> [usize; N] {
+    let record_size = key_size + value_size;
+
+    // Heapsort the record indices by key.
+    let mut sorted = [0; N];
+    let mut i = 0;
+    while i < N {
+        sorted[i] = i;
+        i += 1;
+    }
+
+    let mut start = N / 2;
+    let mut end = N;
+    while end > 1 {
+        if start > 0 {
+            start -= 1;
+        } else {
+            end -= 1;
+            let top = sorted[0];
+            sorted[0] = sorted[end];
+            sorted[end] = top;
+        }
+
+        let mut root = start;
+        loop {
+            let mut child = 2 * root + 1;
+            if child >= end {
+                break;
+            }
+            if child + 1 < end
+                && compare_keys(
+                    bytes,
+                    sorted[child],
+                    sorted[child + 1],
+                    record_size,
+                    key_size,
+                )
+                .is_lt()
+            {
+                child += 1;
+            }
+            if compare_keys(bytes, sorted[root], sorted[child], record_size, key_size).is_lt() {
+                let parent = sorted[root];
+                sorted[root] = sorted[child];
+                sorted[child] = parent;
+                root = child;
+            } else {
+                break;
+            }
+        }
+    }
+
+    let mut i = 1;
+    while i < N {
+        assert!(
+            !compare_keys(bytes, sorted[i - 1], sorted[i], record_size, key_size).is_eq(),
+            "Included map contains duplicate keys",
+        );
+        i += 1;
+    }
+
+    // Place the sorted records by an in-order traversal of the implicit tree
+    // in which node `k` (counting from 1) has children `2k` and `2k + 1`.
+    let mut out = [0; N];
+    let mut node = 1;
+    while 2 * node <= N {
+        node *= 2;
+    }
+    let mut i = 0;
+    while node != 0 && node <= N {
+        out[node - 1] = sorted[i];
+        i += 1;
+
+        if 2 * node < N {
+            node = 2 * node + 1;
+            while 2 * node <= N {
+                node *= 2;
+            }
+        } else {
+            while node % 2 == 1 {
+                node /= 2;
+            }
+            node /= 2;
+        }
+    }
+    out
+}
+
+/// Copy `size` bytes at `offset` in each record, in the given `order`, into an
+/// array of `N` bytes.
+#[doc(hidden)]
+#[must_use]
+#[allow(clippy::module_name_repetitions)]
+pub const fn map_permute<const N: usize>(
+    bytes: &[u8],
+    order: &[usize],
+    record_size: usize,
+    offset: usize,
+    size: usize,
+) -> [u8; N] {
+    assert!(order.len() * size == N, "Wrong length of map data");
+    let mut out = [0; N];
+    let mut i = 0;
+    while i < order.len() {
+        let mut j = 0;
+        while j < size {
+            out[i * size + j] = bytes[order[i] * record_size + offset + j];
+            j += 1;
+        }
+        i += 1;
+    }
+    out
+}
+
+/// Compare the keys of records `left` and `right` by their bytes.
+const fn compare_keys(
+    bytes: &[u8],
+    left: usize,
+    right: usize,
+    record_size: usize,
+    key_size: usize,
+) -> Ordering {
+    let mut i = 0;
+    while i < key_size {
+        let left_byte = bytes[left * record_size + i];
+        let right_byte = bytes[right * record_size + i];
+        if left_byte < right_byte {
+            return Ordering::Less;
+        }
+        if left_byte > right_byte {
+            return Ordering::Greater;
+        }
+        i += 1;
+    }
+    Ordering::Equal
+}
+
+/// Include a file of key/value records as a [`StaticMap`], built at compile
+/// time.
+///
+/// `include_map!(K, V, path)` reads the file at `path` as a sequence of
+/// records, each of which is the bytes of a key of type `K` immediately
+/// followed by the bytes of a value of type `V`, with no padding. Such files
+/// can be written from a slice of `(K, V)` pairs with `write_map` from the
+/// companion crate `include_data_build`. `K` must implement
+/// [`bytemuck::Pod`], so that keys can be compared by their bytes, and `V`
+/// must implement [`bytemuck::AnyBitPattern`].
+///
+/// The keys are sorted at compile time, and a compiler error will be thrown if
+/// any key appears more than once, or if the file size is not divisible by the
+/// size of one record. Only the rearranged keys and values are included in the
+/// executable. Since the map is built by `const` evaluation, maps with many
+/// thousands of entries noticeably increase compile times. Paths are
+/// interpreted by [`core::include_bytes`].
+///
+/// # Example
+///
+/// ```
+/// # use include_data::{include_map, StaticMap};
+/// // Records of a `u16` key and a `u16` value.
+/// static HANDLERS: StaticMap<u16, u16> = include_map!(u16, u16, "../tests/test_data/binary_32");
+///
+/// let opcode = u16::from_ne_bytes([4, 5]);
+/// assert_eq!(HANDLERS.get(&opcode), Some(&u16::from_ne_bytes([6, 7])));
+/// assert_eq!(HANDLERS.get(&0xffff), None);
+/// ```
+#[macro_export]
+#[allow(clippy::module_name_repetitions)]
+macro_rules! include_map {
+    ($key_ty:ty, $value_ty:ty, $file:expr $(,)?) => {{
+        const KEY_SIZE: usize = ::core::mem::size_of::<$key_ty>();
+        const VALUE_SIZE: usize = ::core::mem::size_of::<$value_ty>();
+        const BYTES: &[u8] = ::core::include_bytes!($file);
+        const LEN: usize = $crate::map_len(BYTES, KEY_SIZE, VALUE_SIZE);
+        const ORDER: [usize; LEN] = $crate::map_order(BYTES, KEY_SIZE, VALUE_SIZE);
+
+        const KEYS: &$crate::AlignedAs<$key_ty, [u8]> = &$crate::AlignedAs {
+            _align: [],
+            bytes: $crate::map_permute::<{ LEN * KEY_SIZE }>(
+                BYTES,
+                &ORDER,
+                KEY_SIZE + VALUE_SIZE,
+                0,
+                KEY_SIZE,
+            ),
+        };
+        const VALUES: &$crate::AlignedAs<$value_ty, [u8]> = &$crate::AlignedAs {
+            _align: [],
+            bytes: $crate::map_permute::<{ LEN * VALUE_SIZE }>(
+                BYTES,
+                &ORDER,
+                KEY_SIZE + VALUE_SIZE,
+                KEY_SIZE,
+                VALUE_SIZE,
+            ),
+        };
+
+        const MAP: $crate::StaticMap<$key_ty, $value_ty> = unsafe {
+            $crate::StaticMap::from_eytzinger(
+                ::core::slice::from_raw_parts(KEYS.bytes.as_ptr().cast(), LEN),
+                ::core::slice::from_raw_parts(VALUES.bytes.as_ptr().cast(), LEN),
+            )
+        };
+
+        MAP
+    }};
+}
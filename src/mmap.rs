@@ -0,0 +1,177 @@
+use core::fmt;
+use core::mem::{align_of, size_of};
+use core::ops::Deref;
+
+use std::fs::File;
+use std::io;
+use std::path::Path;
+
+/// A slice of [`bytemuck::AnyBitPattern`] data, either embedded in the
+/// executable or memory-mapped from a file at runtime.
+///
+/// Both kinds dereference to `[T]`, so code using the data does not depend on
+/// where it is stored. This makes it possible to switch large data sets
+/// between being embedded with [`include_slice`](crate::include_slice) and
+/// being stored in a separate file (which keeps compile times down and
+/// executables small) with a single `cfg` flag:
+///
+/// ```no_run
+/// # use include_data::{include_slice, IncludedSlice};
+/// # fn main() -> Result<(), include_data::MmapError> {
+/// #[cfg(feature = "embed-data")]
+/// let table = IncludedSlice::embedded(include_slice!(u32, "../tests/test_data/binary_32"));
+/// #[cfg(not(feature = "embed-data"))]
+/// // Safety: the file is not modified while it is mapped.
+/// let table: IncludedSlice<u32> = unsafe { IncludedSlice::map_next_to_exe("binary_32")? };
+///
+/// let total: u32 = table.iter().sum();
+/// # Ok(())
+/// # }
+/// ```
+///
+/// Mapped files are checked at runtime in the same way that `include_slice`
+/// checks included files at compile time: an error is returned if the file
+/// size is not divisible by [`size_of::<T>()`][core::mem::size_of], or if the
+/// mapping is not correctly aligned for `T` (which only happens if `T` has an
+/// alignment greater than the page size).
+///
+/// Requires the `mmap` feature, which requires `std`.
+pub struct IncludedSlice<T: 'static> {
+    storage: Storage<T>,
+}
+
+enum Storage<T: 'static> {
+    Embedded(&'static [T]),
+    Mapped { map: memmap2::Mmap, len: usize },
+}
+
+impl<T: bytemuck::AnyBitPattern> IncludedSlice<T> {
+    /// Wrap data embedded in the executable, usually with
+    /// [`include_slice`](crate::include_slice).
+    #[must_use]
+    pub const fn embedded(data: &'static [T]) -> Self {
+        Self {
+            storage: Storage::Embedded(data),
+        }
+    }
+
+    /// Memory-map the file at `path`.
+    ///
+    /// # Errors
+    ///
+    /// If the file cannot be opened or mapped, if its size is not divisible by
+    /// [`size_of::<T>()`][core::mem::size_of], or if the mapping is not
+    /// correctly aligned for `T`.
+    ///
+    /// # Safety
+    ///
+    /// The file must not be modified or truncated (by this or any other
+    /// process) while it is mapped. See [`memmap2::Mmap`] for details.
+    pub unsafe fn map(path: impl AsRef<Path>) -> Result<Self, MmapError> {
+        let file = File::open(path)?;
+        let map = memmap2::Mmap::map(&file)?;
+
+        if map.len().checked_rem(size_of::<T>()) != Some(0) {
+            return Err(MmapError::Size);
+        }
+        if map.as_ptr().align_offset(align_of::<T>()) != 0 {
+            return Err(MmapError::Alignment);
+        }
+
+        Ok(Self {
+            storage: Storage::Mapped {
+                len: map.len() / size_of::<T>(),
+                map,
+            },
+        })
+    }
+
+    /// Memory-map the file `name` in the directory containing the current
+    /// executable.
+    ///
+    /// # Errors
+    ///
+    /// As [`IncludedSlice::map`], or if the path of the current executable
+    /// cannot be found.
+    ///
+    /// # Safety
+    ///
+    /// As [`IncludedSlice::map`].
+    pub unsafe fn map_next_to_exe(name: impl AsRef<Path>) -> Result<Self, MmapError> {
+        let exe = std::env::current_exe()?;
+        let dir = exe.parent().ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::NotFound,
+                "the current executable has no parent directory",
+            )
+        })?;
+        Self::map(dir.join(name))
+    }
+
+    /// Whether the data is memory-mapped, rather than embedded.
+    #[must_use]
+    pub const fn is_mapped(&self) -> bool {
+        matches!(self.storage, Storage::Mapped { .. })
+    }
+}
+
+impl<T: bytemuck::AnyBitPattern> Deref for IncludedSlice<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        match &self.storage {
+            Storage::Embedded(data) => data,
+            // Safety: the map's size and alignment were checked when it was
+            // created, every bit pattern is valid for `T: AnyBitPattern`, and
+            // the map lives (unmodified, by the contract of `map`) for as
+            // long as `self`.
+            Storage::Mapped { map, len, .. } => unsafe {
+                core::slice::from_raw_parts(map.as_ptr().cast(), *len)
+            },
+        }
+    }
+}
+
+impl<T: bytemuck::AnyBitPattern + fmt::Debug> fmt::Debug for IncludedSlice<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+/// Why a file could not be memory-mapped as an [`IncludedSlice`].
+#[derive(Debug)]
+#[non_exhaustive]
+#[allow(clippy::module_name_repetitions)]
+pub enum MmapError {
+    /// The file could not be opened or mapped.
+    Io(io::Error),
+    /// The file size is not divisible by the target type size.
+    Size,
+    /// The mapping is not correctly aligned for the target type.
+    Alignment,
+}
+
+impl fmt::Display for MmapError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "Could not open or map file: {err}"),
+            Self::Size => f.write_str("Mapped file size is not divisible by target type size"),
+            Self::Alignment => f.write_str("Mapped file is not aligned for target type"),
+        }
+    }
+}
+
+impl std::error::Error for MmapError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for MmapError {
+    fn from(err: io::Error) -> Self {
+        Self::Io(err)
+    }
+}
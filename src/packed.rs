@@ -0,0 +1,406 @@
+use core::fmt;
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+
+use crate::Endian;
+
+/// Integer types which values of a [`PackedSlice`] can be read as.
+#[allow(clippy::module_name_repetitions)]
+pub trait PackedValue: Copy {
+    #[doc(hidden)]
+    const BITS: u32;
+
+    #[doc(hidden)]
+    fn from_bits(bits: u32) -> Self;
+}
+
+macro_rules! impl_packed_value {
+    ($($ty:ty),*) => {
+        $(
+            impl PackedValue for $ty {
+                const BITS: u32 = <$ty>::BITS;
+
+                #[allow(clippy::cast_possible_truncation)]
+                fn from_bits(bits: u32) -> Self {
+                    // Cannot truncate, since values are at most `Self::BITS`
+                    // wide.
+                    bits as $ty
+                }
+            }
+        )*
+    };
+}
+
+impl_packed_value!(u8, u16, u32);
+
+/// A read-only slice of unsigned integers of any width up to 32 bits, packed
+/// together without padding, usually included with
+/// [`include_packed`](crate::include_packed).
+///
+/// Values are read as `T`, which must be at least as wide as the packed
+/// values. The bytes are treated as one stream of bits, in which value `i`
+/// occupies bits `i * bits` to `(i + 1) * bits`:
+/// - [`Endian::Little`] - the stream starts at the least significant bit of
+///   each byte, and each value is stored least significant bit first. For
+///   example, 24-bit values are three little-endian bytes, and the first
+///   12-bit value is the first byte and the low nibble of the second.
+/// - [`Endian::Big`] - the stream starts at the most significant bit of each
+///   byte, and each value is stored most significant bit first. For example,
+///   24-bit values are three big-endian bytes, and the first 12-bit value is
+///   the first byte and the high nibble of the second.
+///
+/// Values are unpacked on each access, so the data stays packed in the
+/// executable and is never copied into RAM.
+#[allow(clippy::module_name_repetitions)]
+pub struct PackedSlice<T> {
+    bytes: &'static [u8],
+    len: usize,
+    bits: u32,
+    endian: Endian,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T: PackedValue> PackedSlice<T> {
+    /// All of the values of width `bits` which fit in `bytes`.
+    ///
+    /// # Panics
+    ///
+    /// If `bits` is zero or wider than `T`.
+    #[must_use]
+    pub const fn new(bytes: &'static [u8], bits: u32, endian: Endian) -> Self {
+        assert!(bits > 0, "Packed values must be at least one bit wide");
+        Self::with_len(bytes, bytes.len() * 8 / bits as usize, bits, endian)
+    }
+
+    /// The first `len` values of width `bits` in `bytes`.
+    ///
+    /// # Panics
+    ///
+    /// If `bits` is zero or wider than `T`, or if `bytes` is too short to
+    /// hold `len` values.
+    #[must_use]
+    pub const fn with_len(bytes: &'static [u8], len: usize, bits: u32, endian: Endian) -> Self {
+        assert!(
+            bits > 0 && bits <= T::BITS,
+            "Packed values must be between one bit and the width of the target type",
+        );
+        assert!(
+            len <= bytes.len() * 8 / bits as usize,
+            "Too few bytes for packed values",
+        );
+        Self {
+            bytes,
+            len,
+            bits,
+            endian,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Create a `PackedSlice` from an included file, checking its length.
+    #[doc(hidden)]
+    #[must_use]
+    pub const fn from_included(bytes: &'static [u8], options: PackedOptions) -> Self {
+        let packed = match options.len {
+            Some(len) => Self::with_len(bytes, len, options.bits, options.endian),
+            None => Self::new(bytes, options.bits, options.endian),
+        };
+        // The file must not have a whole unused byte at the end.
+        assert!(
+            (packed.len * packed.bits as usize + 7) / 8 == bytes.len(),
+            "Included file size does not match packed value width",
+        );
+        packed
+    }
+}
+
+impl<T> PackedSlice<T> {
+    /// The number of values.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether there are no values.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The width of each value in bits.
+    #[must_use]
+    pub const fn bits(&self) -> u32 {
+        self.bits
+    }
+
+    /// How the values are packed.
+    #[must_use]
+    pub const fn endian(&self) -> Endian {
+        self.endian
+    }
+
+    /// The packed bytes, including any unused bits at the end.
+    #[must_use]
+    pub const fn as_bytes(&self) -> &'static [u8] {
+        self.bytes
+    }
+
+    /// The packed bits of the value at `index`, which must be in bounds.
+    const fn read(&self, index: usize) -> u32 {
+        let bits = self.bits as usize;
+        let start = index * bits;
+        let first = start / 8;
+        let last = (start + bits - 1) / 8;
+
+        // At most 32 bits starting at any offset span at most 5 bytes.
+        let mut window: u64 = 0;
+        let mut i = first;
+        while i <= last {
+            let byte = self.bytes[i] as u64;
+            match self.endian {
+                Endian::Little => window |= byte << (8 * (i - first)),
+                Endian::Big => window = (window << 8) | byte,
+            }
+            i += 1;
+        }
+
+        let shift = match self.endian {
+            Endian::Little => start % 8,
+            Endian::Big => (last - first + 1) * 8 - start % 8 - bits,
+        };
+        let mask = (1 << bits) - 1;
+        // Cannot truncate, since the value is masked to at most 32 bits.
+        #[allow(clippy::cast_possible_truncation)]
+        let value = (window >> shift & mask) as u32;
+        value
+    }
+}
+
+impl<T: PackedValue> PackedSlice<T> {
+    /// The value at `index`, or `None` if it is out of bounds.
+    #[must_use]
+    pub fn get(&self, index: usize) -> Option<T> {
+        (index < self.len).then(|| T::from_bits(self.read(index)))
+    }
+
+    /// Iterate over the values in order.
+    #[must_use]
+    pub const fn iter(&self) -> PackedIter<T> {
+        PackedIter {
+            packed: *self,
+            front: 0,
+            back: self.len,
+        }
+    }
+}
+
+impl<T> Clone for PackedSlice<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for PackedSlice<T> {}
+
+impl<T: PackedValue> IntoIterator for PackedSlice<T> {
+    type Item = T;
+    type IntoIter = PackedIter<T>;
+
+    fn into_iter(self) -> PackedIter<T> {
+        self.iter()
+    }
+}
+
+impl<T: PackedValue> IntoIterator for &PackedSlice<T> {
+    type Item = T;
+    type IntoIter = PackedIter<T>;
+
+    fn into_iter(self) -> PackedIter<T> {
+        self.iter()
+    }
+}
+
+impl<T: PackedValue + fmt::Debug> fmt::Debug for PackedSlice<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self).finish()
+    }
+}
+
+/// An iterator over the values of a [`PackedSlice`].
+#[allow(clippy::module_name_repetitions)]
+pub struct PackedIter<T> {
+    packed: PackedSlice<T>,
+    front: usize,
+    back: usize,
+}
+
+impl<T: PackedValue> Iterator for PackedIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.front == self.back {
+            return None;
+        }
+        self.front += 1;
+        self.packed.get(self.front - 1)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl<T: PackedValue> DoubleEndedIterator for PackedIter<T> {
+    fn next_back(&mut self) -> Option<T> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        self.packed.get(self.back)
+    }
+}
+
+impl<T: PackedValue> ExactSizeIterator for PackedIter<T> {}
+
+impl<T: PackedValue> FusedIterator for PackedIter<T> {}
+
+impl<T> Clone for PackedIter<T> {
+    fn clone(&self) -> Self {
+        Self {
+            packed: self.packed,
+            front: self.front,
+            back: self.back,
+        }
+    }
+}
+
+impl<T: PackedValue + fmt::Debug> fmt::Debug for PackedIter<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+/// Options for [`include_packed`](crate::include_packed), set by the macro.
+#[doc(hidden)]
+#[derive(Clone, Copy, Debug)]
+#[allow(clippy::module_name_repetitions)]
+pub struct PackedOptions {
+    pub bits: u32,
+    pub endian: Endian,
+    pub len: Option<usize>,
+}
+
+impl PackedOptions {
+    #[must_use]
+    pub const fn new(bits: u32) -> Self {
+        Self {
+            bits,
+            endian: Endian::NATIVE,
+            len: None,
+        }
+    }
+}
+
+/// Include a file of packed unsigned integers of any width up to 32 bits as a
+/// [`PackedSlice`].
+///
+/// `include_packed!(T, bits = N, path)` reads the file at `path` as values of
+/// `N` bits each, packed together without padding, which are read as `T`
+/// (one of `u8`, `u16` or `u32`, at least `N` bits wide). See [`PackedSlice`]
+/// for exactly how values are packed. Aliases are provided for common widths:
+/// [`include_u4s`](crate::include_u4s), [`include_u12s`](crate::include_u12s)
+/// and [`include_u24s`](crate::include_u24s).
+///
+/// Options may follow the path, in any order:
+/// - `endian = little` or `endian = big` - how values are packed, by default
+///   the endianness of the compilation target
+/// - `len = N` - the exact number of values
+///
+/// A compiler error will be thrown if the file size does not match: that is,
+/// if there are enough unused bits at the end of the file for another whole
+/// byte, or with `len`, if the file is not exactly long enough to hold `len`
+/// values. Each option value must be a single token, so longer expressions
+/// must be wrapped in parentheses.
+///
+/// This can assign to both `static` and `const` variables, and paths are
+/// interpreted by [`core::include_bytes`].
+///
+/// # Example
+///
+/// ```
+/// # use include_data::{include_packed, PackedSlice};
+/// static SAMPLES: PackedSlice<u16> = include_packed!(u16, bits = 12, "../tests/test_data/binary_12", endian = big);
+///
+/// assert_eq!(SAMPLES.len(), 8);
+/// assert_eq!(SAMPLES.get(0), Some(0x000));
+/// assert_eq!(SAMPLES.get(1), Some(0x102));
+/// ```
+#[macro_export]
+#[allow(clippy::module_name_repetitions)]
+macro_rules! include_packed {
+    ($target_ty:ty, bits = $bits:tt, $file:expr $(, $option:ident = $value:tt)* $(,)?) => {{
+        const OPTIONS: $crate::PackedOptions = {
+            #[allow(unused_mut)]
+            let mut options = $crate::PackedOptions::new($bits);
+            $($crate::__packed_option!(options, $option = $value);)*
+            options
+        };
+        const PACKED: $crate::PackedSlice<$target_ty> =
+            $crate::PackedSlice::from_included(::core::include_bytes!($file), OPTIONS);
+
+        PACKED
+    }};
+}
+
+/// Set one option of [`include_packed`].
+#[doc(hidden)]
+#[macro_export]
+#[allow(clippy::module_name_repetitions)]
+macro_rules! __packed_option {
+    ($options:ident, endian = little) => {
+        $options.endian = $crate::Endian::Little
+    };
+    ($options:ident, endian = big) => {
+        $options.endian = $crate::Endian::Big
+    };
+    ($options:ident, len = $len:tt) => {
+        $options.len = ::core::option::Option::Some($len)
+    };
+    ($options:ident, $option:ident = $value:tt) => {
+        ::core::compile_error!(::core::concat!(
+            "Unknown option `",
+            ::core::stringify!($option),
+            " = ",
+            ::core::stringify!($value),
+            "`, expected one of `endian = little`, `endian = big` or `len = N`",
+        ))
+    };
+}
+
+/// Alias of [`include_packed(u8, bits = 4, path)`](crate::include_packed).
+/// Returns a `PackedSlice<u8>` of 4-bit values.
+#[macro_export]
+macro_rules! include_u4s {
+    ($($args:tt)*) => {
+        $crate::include_packed!(u8, bits = 4, $($args)*)
+    };
+}
+
+/// Alias of [`include_packed(u16, bits = 12, path)`](crate::include_packed).
+/// Returns a `PackedSlice<u16>` of 12-bit values.
+#[macro_export]
+macro_rules! include_u12s {
+    ($($args:tt)*) => {
+        $crate::include_packed!(u16, bits = 12, $($args)*)
+    };
+}
+
+/// Alias of [`include_packed(u32, bits = 24, path)`](crate::include_packed).
+/// Returns a `PackedSlice<u32>` of 24-bit values.
+#[macro_export]
+macro_rules! include_u24s {
+    ($($args:tt)*) => {
+        $crate::include_packed!(u32, bits = 24, $($args)*)
+    };
+}
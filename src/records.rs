@@ -0,0 +1,378 @@
+use core::iter::FusedIterator;
+
+/// How the records in a file included with
+/// [`include_records`](crate::include_records) are delimited.
+///
+/// Length prefixes are unsigned integers giving the length of the payload in
+/// bytes, in the endianness of the compilation target.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RecordFormat {
+    /// Each record is a `u8` length followed by that many bytes.
+    PrefixU8,
+    /// Each record is a `u16` length followed by that many bytes.
+    PrefixU16,
+    /// Each record is a `u32` length followed by that many bytes.
+    PrefixU32,
+    /// Each record is a `u64` length followed by that many bytes.
+    PrefixU64,
+    /// Each record is followed by the given terminator byte, such as `b'\0'`
+    /// for NUL-terminated strings. The terminator is not part of the record.
+    Terminated(u8),
+}
+
+impl RecordFormat {
+    /// The size in bytes of the length prefix of each record, or zero.
+    const fn prefix_width(self) -> usize {
+        match self {
+            Self::PrefixU8 => 1,
+            Self::PrefixU16 => 2,
+            Self::PrefixU32 => 4,
+            Self::PrefixU64 => 8,
+            Self::Terminated(_) => 0,
+        }
+    }
+
+    /// The start and end of the payload of the record starting at `pos`, and
+    /// the start of the next record.
+    ///
+    /// Panics if the record is truncated.
+    const fn record_at(self, bytes: &[u8], pos: usize) -> (usize, usize, usize) {
+        if let Self::Terminated(terminator) = self {
+            let mut end = pos;
+            while end < bytes.len() {
+                if bytes[end] == terminator {
+                    return (pos, end, end + 1);
+                }
+                end += 1;
+            }
+            panic!("Included file ends with an unterminated record");
+        }
+
+        let width = self.prefix_width();
+        assert!(
+            bytes.len() - pos >= width,
+            "Included file ends with a truncated length prefix",
+        );
+
+        let mut len: u64 = 0;
+        let mut i = 0;
+        while i < width {
+            let byte = bytes[pos + i] as u64;
+            if cfg!(target_endian = "little") {
+                len |= byte << (8 * i);
+            } else {
+                len = (len << 8) | byte;
+            }
+            i += 1;
+        }
+
+        let start = pos + width;
+        let available = bytes.len() - start;
+        assert!(
+            len <= available as u64,
+            "Included file contains a record length past the end of the file",
+        );
+        // Cannot truncate, since it is at most `available`.
+        #[allow(clippy::cast_possible_truncation)]
+        let end = start + len as usize;
+
+        (start, end, end)
+    }
+}
+
+/// Variable-length records included with
+/// [`include_records`](crate::include_records).
+///
+/// Each record is a `&'static [u8]` pointing directly into the included file,
+/// without copying. Records can be iterated over in order or, with
+/// [`Records::get`], accessed by index: this takes constant time if the
+/// records were included with an offset table (see
+/// [`Records::is_indexed`]), and otherwise walks the records from the start.
+#[derive(Clone, Copy, Debug)]
+pub struct Records {
+    bytes: &'static [u8],
+    format: RecordFormat,
+    len: usize,
+    /// The start of each record (including any length prefix), followed by
+    /// the end of the file.
+    offsets: Option<&'static [usize]>,
+}
+
+impl Records {
+    /// Interpret `bytes` as records in the given format.
+    ///
+    /// Usually created with [`include_records`](crate::include_records), which
+    /// evaluates this at compile time.
+    ///
+    /// # Panics
+    ///
+    /// If any record is truncated: that is, a length prefix or the length it
+    /// gives runs past the end of `bytes`, or the last record is missing its
+    /// terminator.
+    #[must_use]
+    pub const fn new(bytes: &'static [u8], format: RecordFormat) -> Self {
+        let mut len = 0;
+        let mut pos = 0;
+        while pos < bytes.len() {
+            pos = format.record_at(bytes, pos).2;
+            len += 1;
+        }
+
+        Self {
+            bytes,
+            format,
+            len,
+            offsets: None,
+        }
+    }
+
+    /// Add an offset table from [`record_offsets`], which must have been
+    /// computed for these records.
+    #[doc(hidden)]
+    #[must_use]
+    pub const fn with_offsets(self, offsets: &'static [usize]) -> Self {
+        assert!(
+            offsets.len() == self.len + 1,
+            "Offset table does not match the records",
+        );
+
+        Self {
+            offsets: Some(offsets),
+            ..self
+        }
+    }
+
+    /// The number of records.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether there are no records.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Whether an offset table is available, so that [`Records::get`] takes
+    /// constant time.
+    #[must_use]
+    pub const fn is_indexed(&self) -> bool {
+        self.offsets.is_some()
+    }
+
+    /// How the records are delimited.
+    #[must_use]
+    pub const fn format(&self) -> RecordFormat {
+        self.format
+    }
+
+    /// The whole included file, including length prefixes or terminators.
+    #[must_use]
+    pub const fn as_bytes(&self) -> &'static [u8] {
+        self.bytes
+    }
+
+    /// The record at `index`, or `None` if it is out of bounds.
+    #[must_use]
+    pub fn get(&self, index: usize) -> Option<&'static [u8]> {
+        if index >= self.len {
+            return None;
+        }
+
+        match self.offsets {
+            Some(offsets) => {
+                let (start, end, _) = self.format.record_at(self.bytes, offsets[index]);
+                Some(&self.bytes[start..end])
+            }
+            None => self.iter().nth(index),
+        }
+    }
+
+    /// Iterate over the records in order.
+    #[must_use]
+    pub const fn iter(&self) -> RecordsIter {
+        RecordsIter {
+            bytes: self.bytes,
+            format: self.format,
+            pos: 0,
+            remaining: self.len,
+        }
+    }
+}
+
+impl IntoIterator for Records {
+    type Item = &'static [u8];
+    type IntoIter = RecordsIter;
+
+    fn into_iter(self) -> RecordsIter {
+        self.iter()
+    }
+}
+
+impl IntoIterator for &Records {
+    type Item = &'static [u8];
+    type IntoIter = RecordsIter;
+
+    fn into_iter(self) -> RecordsIter {
+        self.iter()
+    }
+}
+
+/// An iterator over [`Records`].
+#[derive(Clone, Debug)]
+#[allow(clippy::module_name_repetitions)]
+pub struct RecordsIter {
+    bytes: &'static [u8],
+    format: RecordFormat,
+    pos: usize,
+    remaining: usize,
+}
+
+impl Iterator for RecordsIter {
+    type Item = &'static [u8];
+
+    fn next(&mut self) -> Option<&'static [u8]> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let (start, end, next) = self.format.record_at(self.bytes, self.pos);
+        self.pos = next;
+        self.remaining -= 1;
+        Some(&self.bytes[start..end])
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl ExactSizeIterator for RecordsIter {}
+
+impl FusedIterator for RecordsIter {}
+
+/// The offset of the start of each of `N - 1` records, followed by the length
+/// of `bytes`.
+#[doc(hidden)]
+#[must_use]
+pub const fn record_offsets<const N: usize>(bytes: &[u8], format: RecordFormat) -> [usize; N] {
+    let mut out = [0; N];
+    let mut i = 0;
+    while i + 1 < N {
+        out[i + 1] = format.record_at(bytes, out[i]).2;
+        i += 1;
+    }
+    assert!(
+        out[N - 1] == bytes.len(),
+        "Offset table does not match the records",
+    );
+    out
+}
+
+/// Include a file of variable-length records, such as a string table or a
+/// message log, as [`Records`].
+///
+/// Records may be length-prefixed or terminated by a delimiter byte:
+/// - `include_records!(prefix = u32, path)` - each record is a `u32` length
+///   in bytes, in the endianness of the compilation target, followed by that
+///   many bytes. The prefix may also be `u8`, `u16` or `u64`.
+/// - `include_records!(terminator = b'\0', path)` - each record is followed by
+///   the given byte, which is not part of the record.
+///
+/// The file is validated at compile time: a compiler error will be thrown if
+/// any record is truncated, that is if a length prefix or the length it gives
+/// runs past the end of the file, or if the last record is missing its
+/// terminator.
+///
+/// Accessing a record by index with [`Records::get`] walks the records from
+/// the start of the file. Adding `indexed`, as in
+/// `include_records!(prefix = u32, path, indexed)`, precomputes a table of
+/// offsets at compile time so that this takes constant time, at the cost of
+/// one `usize` per record in the executable.
+///
+/// This can assign to both `static` and `const` variables, and paths are
+/// interpreted by [`core::include_bytes`].
+///
+/// # Example
+///
+/// ```
+/// # use include_data::{include_records, Records};
+/// static NAMES: Records = include_records!(terminator = b'\0', "../tests/test_data/records_nul");
+/// static MESSAGES: Records = include_records!(prefix = u32, "../tests/test_data/records_u32", indexed);
+///
+/// for name in NAMES.iter() {
+///     let name = core::str::from_utf8(name).unwrap();
+///     // ...
+/// }
+/// assert_eq!(MESSAGES.get(1), Some(&b"four"[..]));
+/// ```
+#[macro_export]
+#[allow(clippy::module_name_repetitions)]
+macro_rules! include_records {
+    (prefix = $prefix:ident, $file:expr $(, $option:ident)? $(,)?) => {
+        $crate::__include_records!($crate::__record_prefix!($prefix), $file $(, $option)?)
+    };
+    (terminator = $terminator:expr, $file:expr $(, $option:ident)? $(,)?) => {
+        $crate::__include_records!(
+            $crate::RecordFormat::Terminated($terminator),
+            $file
+            $(, $option)?
+        )
+    };
+}
+
+/// Implementation of [`include_records`] for a given [`RecordFormat`].
+#[doc(hidden)]
+#[macro_export]
+#[allow(clippy::module_name_repetitions)]
+macro_rules! __include_records {
+    ($format:expr, $file:expr) => {{
+        const RECORDS: $crate::Records =
+            $crate::Records::new(::core::include_bytes!($file), $format);
+
+        RECORDS
+    }};
+    ($format:expr, $file:expr, indexed) => {{
+        const RECORDS: $crate::Records =
+            $crate::Records::new(::core::include_bytes!($file), $format);
+        const OFFSETS: [usize; RECORDS.len() + 1] =
+            $crate::record_offsets(RECORDS.as_bytes(), RECORDS.format());
+        const INDEXED: $crate::Records = RECORDS.with_offsets(&OFFSETS);
+
+        INDEXED
+    }};
+    ($format:expr, $file:expr, $option:ident) => {
+        ::core::compile_error!(::core::concat!(
+            "Unknown option `",
+            ::core::stringify!($option),
+            "`, expected `indexed`",
+        ))
+    };
+}
+
+/// The [`RecordFormat`] for a length prefix type.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __record_prefix {
+    (u8) => {
+        $crate::RecordFormat::PrefixU8
+    };
+    (u16) => {
+        $crate::RecordFormat::PrefixU16
+    };
+    (u32) => {
+        $crate::RecordFormat::PrefixU32
+    };
+    (u64) => {
+        $crate::RecordFormat::PrefixU64
+    };
+    ($other:ident) => {
+        ::core::compile_error!(::core::concat!(
+            "Unsupported length prefix type `",
+            ::core::stringify!($other),
+            "`, expected one of `u8`, `u16`, `u32` or `u64`",
+        ))
+    };
+}
@@ -0,0 +1,148 @@
+/// Include a file as a `&'static [[T; W]]` slice of rows, for matrix or
+/// image-like data.
+///
+/// `include_matrix!(T, cols = W, path)` includes the file at `path` as rows
+/// of `W` elements of type `T`, which must implement
+/// [`bytemuck::AnyBitPattern`]. A compiler error will be thrown if the file
+/// size is not a multiple of the size of one row. With
+/// `include_matrix!(T, rows = H, cols = W, path)`, a compiler error will also
+/// be thrown unless the file contains exactly `H` rows.
+///
+/// Unlike [`include_slice`](crate::include_slice), this can assign to both
+/// `static` and `const` variables. As with other `const`s, every use of a
+/// `const` may duplicate the data, so `static` should be preferred for large
+/// files. Paths are interpreted by [`core::include_bytes`].
+///
+/// This is shorthand for [`include_shaped`](crate::include_shaped), which
+/// supports any number of dimensions.
+///
+/// # Example
+///
+/// ```
+/// # use include_data::include_matrix;
+/// static IMAGE: &[[u8; 8]] = include_matrix!(u8, cols = 8, "../tests/test_data/binary_32");
+/// const MATRIX: &[[u16; 4]] = include_matrix!(u16, rows = 4, cols = 4, "../tests/test_data/binary_32");
+///
+/// assert_eq!(IMAGE.len(), 4);
+/// assert_eq!(IMAGE[1][0], 8);
+/// assert_eq!(MATRIX.len(), 4);
+/// ```
+///
+/// # Safety
+///
+/// This macro is safe. However, if used on a custom type, that type must
+/// implement [`bytemuck::AnyBitPattern`]. Implementing that trait has very
+/// strict safety requirements which must be observed.
+#[macro_export]
+macro_rules! include_matrix {
+    ($target_ty:ty, cols = $cols:expr, $file:expr $(,)?) => {
+        $crate::include_shaped!($target_ty, [_, $cols], $file)
+    };
+    ($target_ty:ty, rows = $rows:expr, cols = $cols:expr, $file:expr $(,)?) => {
+        $crate::include_shaped!($target_ty, [$rows, $cols], $file)
+    };
+}
+
+/// Include a file as a slice of N-dimensional arrays, such as
+/// `&'static [[[T; C]; B]]`.
+///
+/// `include_shaped!(T, [_, B, C], path)` includes the file at `path` as a
+/// slice with elements of type `[[T; C]; B]`, where `T` must implement
+/// [`bytemuck::AnyBitPattern`]. The shape may have any number of dimensions,
+/// outermost first. A compiler error will be thrown if the file size is not a
+/// multiple of the size of one element (here, `B * C` values of type `T`).
+///
+/// If the first dimension is a number rather than `_`, as in
+/// `include_shaped!(T, [A, B, C], path)`, a compiler error will also be thrown
+/// unless the slice has exactly `A` elements.
+///
+/// As with [`include_matrix`](crate::include_matrix), this can assign to both
+/// `static` and `const` variables, and paths are interpreted by
+/// [`core::include_bytes`].
+///
+/// # Example
+///
+/// ```
+/// # use include_data::include_shaped;
+/// static VOLUME: &[[[u8; 4]; 2]] = include_shaped!(u8, [_, 2, 4], "../tests/test_data/binary_32");
+/// const EXACT: &[[[u16; 2]; 2]] = include_shaped!(u16, [4, 2, 2], "../tests/test_data/binary_32");
+///
+/// assert_eq!(VOLUME.len(), 4);
+/// assert_eq!(VOLUME[1][1][0], 12);
+/// ```
+///
+/// # Safety
+///
+/// This macro is safe. However, if used on a custom type, that type must
+/// implement [`bytemuck::AnyBitPattern`]. Implementing that trait has very
+/// strict safety requirements which must be observed.
+#[macro_export]
+#[allow(clippy::module_name_repetitions)]
+macro_rules! include_shaped {
+    ($target_ty:ty, [_ $(, $dim:expr)* $(,)?], $file:expr $(,)?) => {
+        $crate::__include_shaped!(
+            $target_ty,
+            [$($dim),*],
+            ::core::option::Option::None,
+            $file,
+        )
+    };
+    ($target_ty:ty, [$rows:expr $(, $dim:expr)* $(,)?], $file:expr $(,)?) => {
+        $crate::__include_shaped!(
+            $target_ty,
+            [$($dim),*],
+            ::core::option::Option::Some($rows),
+            $file,
+        )
+    };
+}
+
+/// Implementation of [`include_shaped`], with the expected number of rows as
+/// an `Option<usize>`.
+#[doc(hidden)]
+#[macro_export]
+#[allow(clippy::module_name_repetitions)]
+macro_rules! __include_shaped {
+    ($target_ty:ty, [$($dim:expr),*], $rows:expr, $file:expr $(,)?) => {{
+        type Row = $crate::__shaped_ty!($target_ty; $($dim),*);
+        const ROW_SIZE: usize = ::core::mem::size_of::<Row>();
+
+        const ALIGNED: &$crate::AlignedAs<Row, [u8]> = &$crate::AlignedAs {
+            _align: [],
+            bytes: *::core::include_bytes!($file),
+        };
+
+        const OUT_SLICE: &[Row] = {
+            let byte_slice: &[u8] = &ALIGNED.bytes;
+
+            assert!(
+                byte_slice.len() % ROW_SIZE == 0,
+                "Included file size is not divisible by row size",
+            );
+            let rows = byte_slice.len() / ROW_SIZE;
+            if let ::core::option::Option::Some(expected) = $rows {
+                assert!(
+                    rows == expected,
+                    "Included file does not contain the expected number of rows",
+                );
+            }
+
+            unsafe { ::core::slice::from_raw_parts(byte_slice.as_ptr().cast(), rows) }
+        };
+
+        OUT_SLICE
+    }};
+}
+
+/// The nested array type with the given dimensions, outermost first.
+#[doc(hidden)]
+#[macro_export]
+#[allow(clippy::module_name_repetitions)]
+macro_rules! __shaped_ty {
+    ($target_ty:ty;) => {
+        $target_ty
+    };
+    ($target_ty:ty; $dim:expr $(, $rest:expr)*) => {
+        [$crate::__shaped_ty!($target_ty; $($rest),*); $dim]
+    };
+}
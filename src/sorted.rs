@@ -0,0 +1,366 @@
+use core::cmp::Ordering;
+use core::fmt;
+use core::ops::{Bound, Deref, RangeBounds};
+
+use crate::const_panic::index_panic;
+
+/// Types whose order can be checked at compile time by
+/// [`include_sorted_slice`](crate::include_sorted_slice).
+///
+/// Implemented for primitive numbers and for arrays of them, which are
+/// ordered lexicographically as by their [`PartialOrd`] implementation.
+///
+/// # Safety
+///
+/// `LAYOUT` must describe the bytes of the type: `LAYOUT.count` numbers of
+/// `LAYOUT.size` bytes each, in the endianness of the compilation target, with
+/// no padding, and ordered as by the type's [`PartialOrd`] implementation.
+/// [`check_sorted`] panics rather than reading out of bounds if the size does
+/// not match, but an incorrect layout may still make it accept an unsorted
+/// slice.
+pub unsafe trait ConstOrd: bytemuck::AnyBitPattern + PartialOrd {
+    #[doc(hidden)]
+    const LAYOUT: OrdLayout;
+}
+
+/// How to compare values of a [`ConstOrd`] type by their bytes: as `count`
+/// numbers of `size` bytes each, compared lexicographically.
+#[doc(hidden)]
+#[derive(Clone, Copy, Debug)]
+pub struct OrdLayout {
+    pub kind: OrdKind,
+    pub size: usize,
+    pub count: usize,
+}
+
+#[doc(hidden)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OrdKind {
+    Unsigned,
+    Signed,
+    Float,
+}
+
+macro_rules! impl_const_ord {
+    ($kind:ident: $($ty:ty),*) => {
+        $(
+            // Safety: a single number of the given kind and size.
+            unsafe impl ConstOrd for $ty {
+                const LAYOUT: OrdLayout = OrdLayout {
+                    kind: OrdKind::$kind,
+                    size: core::mem::size_of::<$ty>(),
+                    count: 1,
+                };
+            }
+        )*
+    };
+}
+
+impl_const_ord!(Unsigned: u8, u16, u32, u64, u128, usize);
+impl_const_ord!(Signed: i8, i16, i32, i64, i128, isize);
+impl_const_ord!(Float: f32, f64);
+
+// Safety: arrays of `Pod` types have no padding, and are ordered
+// lexicographically.
+unsafe impl<T: ConstOrd + bytemuck::Pod, const N: usize> ConstOrd for [T; N] {
+    const LAYOUT: OrdLayout = OrdLayout {
+        count: T::LAYOUT.count * N,
+        ..T::LAYOUT
+    };
+}
+
+/// Panic unless `slice` is sorted in ascending order, strictly if `strict`,
+/// naming the first element out of order.
+#[doc(hidden)]
+#[allow(clippy::module_name_repetitions)]
+pub const fn check_sorted<T: ConstOrd>(slice: &[T], strict: bool) {
+    let layout = T::LAYOUT;
+    let value_size = layout.size * layout.count;
+    assert!(
+        value_size == core::mem::size_of::<T>(),
+        "ConstOrd layout does not match the size of the type",
+    );
+    assert!(
+        layout.size >= 1 && layout.size <= 16,
+        "ConstOrd layout has an unsupported number size",
+    );
+    assert!(
+        !matches!(layout.kind, OrdKind::Float) || layout.size == 4 || layout.size == 8,
+        "ConstOrd layout has an unsupported float size",
+    );
+    // The size of the slice, as `size_of_val` (which is not yet `const`).
+    let byte_len = slice.len() * core::mem::size_of::<T>();
+    // Safety: `slice` is initialized memory of exactly this many bytes, and
+    // `AnyBitPattern` types have no padding.
+    let bytes: &[u8] = unsafe { core::slice::from_raw_parts(slice.as_ptr().cast(), byte_len) };
+
+    let mut i = 0;
+    while i < slice.len() {
+        if matches!(layout.kind, OrdKind::Float) && contains_nan(bytes, i * value_size, layout) {
+            index_panic(b"Included slice contains NaN at element ", i, b"");
+        }
+        if i > 0 {
+            match compare(bytes, (i - 1) * value_size, i * value_size, layout) {
+                Ordering::Greater => {
+                    index_panic(
+                        b"Included slice is not sorted: element ",
+                        i,
+                        b" is less than the element before it",
+                    );
+                }
+                Ordering::Equal if strict => {
+                    index_panic(
+                        b"Included slice is not strictly sorted: element ",
+                        i,
+                        b" is equal to the element before it",
+                    );
+                }
+                _ => {}
+            }
+        }
+        i += 1;
+    }
+}
+
+/// The bits of the number of `size` bytes at `offset`.
+const fn read_bits(bytes: &[u8], offset: usize, size: usize) -> u128 {
+    let mut bits = 0;
+    let mut i = 0;
+    while i < size {
+        let byte = bytes[offset + i] as u128;
+        if cfg!(target_endian = "little") {
+            bits |= byte << (8 * i);
+        } else {
+            bits = (bits << 8) | byte;
+        }
+        i += 1;
+    }
+    bits
+}
+
+/// Whether any of the floats in the value at `offset` is NaN.
+const fn contains_nan(bytes: &[u8], offset: usize, layout: OrdLayout) -> bool {
+    let (_, exponent, mantissa) = float_masks(layout.size);
+    let mut i = 0;
+    while i < layout.count {
+        let bits = read_bits(bytes, offset + i * layout.size, layout.size);
+        if bits & exponent == exponent && bits & mantissa != 0 {
+            return true;
+        }
+        i += 1;
+    }
+    false
+}
+
+/// The sign, exponent and mantissa masks of a float of `size` bytes.
+const fn float_masks(size: usize) -> (u128, u128, u128) {
+    let sign = 1 << (8 * size - 1);
+    let mantissa_bits = if size == 4 { 23 } else { 52 };
+    let mantissa = (1 << mantissa_bits) - 1;
+    let exponent = (sign - 1) & !mantissa;
+    (sign, exponent, mantissa)
+}
+
+/// Compare the values at offsets `left` and `right`, which must not contain
+/// NaN.
+const fn compare(bytes: &[u8], left: usize, right: usize, layout: OrdLayout) -> Ordering {
+    let mut i = 0;
+    while i < layout.count {
+        let left_key = order_key(
+            read_bits(bytes, left + i * layout.size, layout.size),
+            layout,
+        );
+        let right_key = order_key(
+            read_bits(bytes, right + i * layout.size, layout.size),
+            layout,
+        );
+        if left_key < right_key {
+            return Ordering::Less;
+        }
+        if left_key > right_key {
+            return Ordering::Greater;
+        }
+        i += 1;
+    }
+    Ordering::Equal
+}
+
+/// Map the bits of a number to an unsigned integer with the same order.
+const fn order_key(bits: u128, layout: OrdLayout) -> u128 {
+    let sign = 1 << (8 * layout.size - 1);
+    match layout.kind {
+        OrdKind::Unsigned => bits,
+        OrdKind::Signed => bits ^ sign,
+        OrdKind::Float => {
+            if bits & !sign == 0 {
+                // Positive and negative zero are equal.
+                sign
+            } else if bits & sign == 0 {
+                bits | sign
+            } else {
+                !bits & ((sign << 1).wrapping_sub(1))
+            }
+        }
+    }
+}
+
+/// A slice which is known to be sorted in ascending order, usually included
+/// with [`include_sorted_slice`](crate::include_sorted_slice).
+///
+/// Dereferences to `[T]`, and additionally provides searches which rely on
+/// the order. Comparisons use [`PartialOrd`], so for floating point types a
+/// NaN key is never found.
+#[allow(clippy::module_name_repetitions)]
+pub struct SortedSlice<T: 'static> {
+    slice: &'static [T],
+}
+
+impl<T> SortedSlice<T> {
+    /// Wrap a slice which is already known to be sorted.
+    #[doc(hidden)]
+    #[must_use]
+    pub const fn new_unchecked(slice: &'static [T]) -> Self {
+        Self { slice }
+    }
+
+    /// The underlying slice.
+    #[must_use]
+    pub const fn as_slice(&self) -> &'static [T] {
+        self.slice
+    }
+}
+
+impl<T: PartialOrd> SortedSlice<T> {
+    /// Wrap `slice`, or return `None` if it is not sorted in ascending order.
+    #[must_use]
+    pub fn new(slice: &'static [T]) -> Option<Self> {
+        slice
+            .windows(2)
+            .all(|pair| pair[0] <= pair[1])
+            .then_some(Self { slice })
+    }
+
+    /// Binary search for `key`, as [`slice::binary_search`].
+    ///
+    /// # Errors
+    ///
+    /// If `key` is not found, the index where it could be inserted while
+    /// keeping the slice sorted.
+    pub fn binary_search(&self, key: &T) -> Result<usize, usize> {
+        let lower = self.lower_bound(key);
+        match self.slice.get(lower) {
+            Some(value) if value == key => Ok(lower),
+            _ => Err(lower),
+        }
+    }
+
+    /// Whether the slice contains `key`, found by binary search.
+    #[must_use]
+    pub fn contains(&self, key: &T) -> bool {
+        self.binary_search(key).is_ok()
+    }
+
+    /// The index of the first element which is not less than `key`, or the
+    /// length of the slice if there is none.
+    #[must_use]
+    pub fn lower_bound(&self, key: &T) -> usize {
+        self.slice.partition_point(|value| value < key)
+    }
+
+    /// The index of the first element which is greater than `key`, or the
+    /// length of the slice if there is none.
+    #[must_use]
+    pub fn upper_bound(&self, key: &T) -> usize {
+        self.slice.partition_point(|value| value <= key)
+    }
+
+    /// The elements within `range`, such as `&10..&20` or `&10..`.
+    #[must_use]
+    pub fn range<'a>(&self, range: impl RangeBounds<&'a T>) -> &'static [T]
+    where
+        T: 'a,
+    {
+        let start = match range.start_bound() {
+            Bound::Included(key) => self.lower_bound(key),
+            Bound::Excluded(key) => self.upper_bound(key),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(key) => self.upper_bound(key),
+            Bound::Excluded(key) => self.lower_bound(key),
+            Bound::Unbounded => self.slice.len(),
+        };
+        self.slice.get(start..end).unwrap_or(&[])
+    }
+}
+
+impl<T> Clone for SortedSlice<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for SortedSlice<T> {}
+
+impl<T> Deref for SortedSlice<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        self.slice
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for SortedSlice<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.slice, f)
+    }
+}
+
+/// As [`include_slice`](crate::include_slice), but checking at compile time
+/// that the included elements are sorted in ascending order, and evaluating to
+/// a [`SortedSlice`].
+///
+/// `include_sorted_slice!(T, path)` allows equal neighbouring elements, while
+/// `include_sorted_slice!(T, path, strict)` requires every element to be
+/// greater than the one before it. Otherwise, a compiler error is thrown
+/// naming the first element out of order. Floating point elements must not
+/// be NaN, and positive and negative zero are equal.
+///
+/// `T` must implement [`ConstOrd`], which is implemented for primitive numbers
+/// and arrays of them. This can assign to both `static` and `const`
+/// variables, and paths are interpreted by [`core::include_bytes`].
+///
+/// # Example
+///
+/// ```
+/// # use include_data::{include_sorted_slice, SortedSlice};
+/// static TABLE: SortedSlice<u8> = include_sorted_slice!(u8, "../tests/test_data/binary_32", strict);
+///
+/// assert_eq!(TABLE.binary_search(&12), Ok(12));
+/// assert_eq!(TABLE.lower_bound(&40), 32);
+/// assert_eq!(TABLE.range(&4..&8), [4, 5, 6, 7]);
+/// ```
+#[macro_export]
+macro_rules! include_sorted_slice {
+    ($target_ty:ty, $file:expr $(,)?) => {
+        $crate::__include_sorted_slice!($target_ty, $file, false)
+    };
+    ($target_ty:ty, $file:expr, strict $(,)?) => {
+        $crate::__include_sorted_slice!($target_ty, $file, true)
+    };
+}
+
+/// Implementation of [`include_sorted_slice`].
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __include_sorted_slice {
+    ($target_ty:ty, $file:expr, $strict:expr) => {{
+        const SLICE: &[$target_ty] = $crate::__include_slice_const!($target_ty, $file);
+        const SORTED: $crate::SortedSlice<$target_ty> = {
+            $crate::check_sorted(SLICE, $strict);
+            $crate::SortedSlice::new_unchecked(SLICE)
+        };
+
+        SORTED
+    }};
+}
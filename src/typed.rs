@@ -0,0 +1,357 @@
+use core::fmt;
+use core::mem::{align_of, size_of};
+
+use crate::{Endian, FingerprintHasher, LayoutFingerprint};
+
+/// The header of the self-describing file format read by [`include_typed`](crate::include_typed).
+///
+/// A typed data file consists of a [`TypedHeader::LEN`]-byte header followed
+/// immediately by the payload: the elements themselves, in order, with no
+/// padding. All header fields are stored little-endian, regardless of the
+/// endianness of the payload. The header layout is:
+///
+/// | Offset | Size | Field                                           |
+/// |--------|------|-------------------------------------------------|
+/// | 0      | 8    | Magic bytes, [`TypedHeader::MAGIC`]             |
+/// | 8      | 2    | Format version, [`TypedHeader::VERSION`]        |
+/// | 10     | 1    | Payload endianness: 0 for little, 1 for big     |
+/// | 11     | 1    | Reserved, must be 0                             |
+/// | 12     | 4    | Element size in bytes                           |
+/// | 16     | 8    | Element type id: its [`LayoutFingerprint`]      |
+/// | 24     | 8    | Element count                                   |
+/// | 32     | 8    | Checksum of the payload, see [`typed_checksum`] |
+/// | 40     | 8    | Reserved, must be 0                             |
+///
+/// The header length is a multiple of 16 bytes, so the payload is correctly
+/// aligned for any element type with an alignment of up to 16 bytes.
+///
+/// # Writing typed data files
+///
+/// [`TypedHeader::for_slice`] creates the header for some data, which can then
+/// be written with [`TypedHeader::to_bytes`] followed by the data itself.
+/// [`write_typed`] does both. Neither requires `std`.
+///
+/// ```
+/// # use include_data::TypedHeader;
+/// let data: [u32; 4] = [1, 2, 3, 4];
+///
+/// let mut file = Vec::new();
+/// file.extend_from_slice(&TypedHeader::for_slice(&data).to_bytes());
+/// file.extend_from_slice(bytemuck::cast_slice(&data));
+///
+/// assert_eq!(file.len(), TypedHeader::LEN + 16);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[allow(clippy::module_name_repetitions)]
+pub struct TypedHeader {
+    /// Format version.
+    pub version: u16,
+    /// Endianness of the payload.
+    pub endian: Endian,
+    /// Size in bytes of each element.
+    pub element_size: u32,
+    /// Element type id, which is the element type's [`LayoutFingerprint`].
+    pub type_id: u64,
+    /// Number of elements in the payload.
+    pub count: u64,
+    /// [`typed_checksum`] of the payload.
+    pub checksum: u64,
+}
+
+impl TypedHeader {
+    /// Length in bytes of the header.
+    pub const LEN: usize = 48;
+    /// Magic bytes at the start of every typed data file.
+    pub const MAGIC: [u8; 8] = *b"INCDATA\0";
+    /// The format version written, and the only version read, by this crate.
+    pub const VERSION: u16 = 1;
+
+    /// Create the header for a slice of data, in native endianness.
+    ///
+    /// # Panics
+    ///
+    /// If the size of `T` does not fit in a `u32`.
+    #[must_use]
+    pub fn for_slice<T: bytemuck::NoUninit + LayoutFingerprint>(data: &[T]) -> Self {
+        Self {
+            version: Self::VERSION,
+            endian: Endian::NATIVE,
+            element_size: u32::try_from(size_of::<T>()).expect("element type is too large"),
+            type_id: T::FINGERPRINT,
+            count: data.len() as u64,
+            checksum: typed_checksum(bytemuck::cast_slice(data)),
+        }
+    }
+
+    /// The header as bytes, as it is stored in a file.
+    #[must_use]
+    pub const fn to_bytes(&self) -> [u8; Self::LEN] {
+        let mut out = [0; Self::LEN];
+        out = write_bytes(out, 0, &Self::MAGIC);
+        out = write_bytes(out, 8, &self.version.to_le_bytes());
+        out[10] = self.endian as u8;
+        out = write_bytes(out, 12, &self.element_size.to_le_bytes());
+        out = write_bytes(out, 16, &self.type_id.to_le_bytes());
+        out = write_bytes(out, 24, &self.count.to_le_bytes());
+        out = write_bytes(out, 32, &self.checksum.to_le_bytes());
+        out
+    }
+
+    /// Parse the header at the start of a typed data file.
+    ///
+    /// This only checks the header itself. Use [`TypedHeader::validate`] to
+    /// check it against an element type and payload.
+    ///
+    /// # Errors
+    ///
+    /// If `file` is too short, does not begin with [`TypedHeader::MAGIC`], is
+    /// of an unsupported version, or has invalid values in the endianness or
+    /// reserved fields.
+    pub const fn parse(file: &[u8]) -> Result<Self, TypedHeaderError> {
+        if file.len() < Self::LEN {
+            return Err(TypedHeaderError::TooShort);
+        }
+
+        let magic: [u8; 8] = crate::copy_bytes(file, 0);
+        let mut i = 0;
+        while i < magic.len() {
+            if magic[i] != Self::MAGIC[i] {
+                return Err(TypedHeaderError::Magic);
+            }
+            i += 1;
+        }
+
+        let version = u16::from_le_bytes(crate::copy_bytes(file, 8));
+        if version != Self::VERSION {
+            return Err(TypedHeaderError::Version);
+        }
+
+        let endian = match file[10] {
+            0 => Endian::Little,
+            1 => Endian::Big,
+            _ => return Err(TypedHeaderError::Endian),
+        };
+
+        if file[11] != 0 || u64::from_le_bytes(crate::copy_bytes(file, 40)) != 0 {
+            return Err(TypedHeaderError::Reserved);
+        }
+
+        Ok(Self {
+            version,
+            endian,
+            element_size: u32::from_le_bytes(crate::copy_bytes(file, 12)),
+            type_id: u64::from_le_bytes(crate::copy_bytes(file, 16)),
+            count: u64::from_le_bytes(crate::copy_bytes(file, 24)),
+            checksum: u64::from_le_bytes(crate::copy_bytes(file, 32)),
+        })
+    }
+
+    /// The payload of a typed data file: everything after the header.
+    ///
+    /// # Panics
+    ///
+    /// If `file` is shorter than the header.
+    #[must_use]
+    pub const fn payload(file: &[u8]) -> &[u8] {
+        assert!(file.len() >= Self::LEN, "file is shorter than the header");
+
+        // Safety: the range `LEN..len()` is within `file`.
+        unsafe { core::slice::from_raw_parts(file.as_ptr().add(Self::LEN), file.len() - Self::LEN) }
+    }
+
+    /// Check that this header and `payload` are valid for a slice of `T`.
+    ///
+    /// # Errors
+    ///
+    /// If the element size, type id or count do not match `T` and `payload`,
+    /// if the payload is not native-endian, if the checksum does not match, or
+    /// if `T` requires greater alignment than the header guarantees.
+    pub const fn validate<T: LayoutFingerprint>(
+        &self,
+        payload: &[u8],
+    ) -> Result<(), TypedHeaderError> {
+        if Self::LEN % align_of::<T>() != 0 {
+            return Err(TypedHeaderError::Alignment);
+        }
+        if self.element_size as usize != size_of::<T>() {
+            return Err(TypedHeaderError::ElementSize);
+        }
+        if self.type_id != T::FINGERPRINT {
+            return Err(TypedHeaderError::TypeId);
+        }
+        match self.count.checked_mul(size_of::<T>() as u64) {
+            Some(len) if len == payload.len() as u64 => {}
+            _ => return Err(TypedHeaderError::Count),
+        }
+        if !self.endian.is_native() {
+            return Err(TypedHeaderError::ForeignEndian);
+        }
+        if self.checksum != typed_checksum(payload) {
+            return Err(TypedHeaderError::Checksum);
+        }
+        Ok(())
+    }
+}
+
+/// Why a [`TypedHeader`] is invalid.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+#[allow(clippy::module_name_repetitions)]
+pub enum TypedHeaderError {
+    /// The file is too short to contain a header.
+    TooShort,
+    /// The file does not begin with [`TypedHeader::MAGIC`].
+    Magic,
+    /// The format version is not [`TypedHeader::VERSION`].
+    Version,
+    /// The endianness field is not a valid value.
+    Endian,
+    /// A reserved field is not zero.
+    Reserved,
+    /// The element size does not match the target type.
+    ElementSize,
+    /// The element type id does not match the target type.
+    TypeId,
+    /// The element count does not match the payload length.
+    Count,
+    /// The payload is not in the endianness of the compilation target.
+    ForeignEndian,
+    /// The checksum does not match the payload.
+    Checksum,
+    /// The target type requires greater alignment than the format guarantees.
+    Alignment,
+}
+
+impl TypedHeaderError {
+    /// A description of the error, usable in `const` contexts.
+    #[must_use]
+    pub const fn message(self) -> &'static str {
+        match self {
+            Self::TooShort => "Included file is too short to contain a typed data header",
+            Self::Magic => "Included file is not a typed data file (wrong magic bytes)",
+            Self::Version => "Included typed data file has an unsupported format version",
+            Self::Endian => "Included typed data file has an invalid endianness field",
+            Self::Reserved => "Included typed data file has non-zero reserved fields",
+            Self::ElementSize => {
+                "Included typed data file element size does not match target type size"
+            }
+            Self::TypeId => "Included typed data file element type does not match target type",
+            Self::Count => "Included typed data file element count does not match its length",
+            Self::ForeignEndian => {
+                "Included typed data file does not match the endianness of the target platform"
+            }
+            Self::Checksum => "Included typed data file checksum does not match its contents",
+            Self::Alignment => "Target type alignment is too large for the typed data format",
+        }
+    }
+}
+
+impl fmt::Display for TypedHeaderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.message())
+    }
+}
+
+/// The checksum of a typed data payload: its 64-bit FNV-1a hash.
+#[must_use]
+#[allow(clippy::module_name_repetitions)]
+pub const fn typed_checksum(payload: &[u8]) -> u64 {
+    FingerprintHasher::new().write(payload).finish()
+}
+
+/// Write a typed data file (header then payload) using the given function to
+/// write bytes, which will be called more than once.
+///
+/// # Errors
+///
+/// Any error returned by `write`.
+///
+/// # Example
+///
+/// ```
+/// # use include_data::{write_typed, TypedHeader};
+/// let mut file = Vec::new();
+/// write_typed(&[1.0_f32, 2.0, 3.0], |bytes| {
+///     file.extend_from_slice(bytes);
+///     Ok::<(), ()>(())
+/// })
+/// .unwrap();
+///
+/// assert_eq!(file.len(), TypedHeader::LEN + 12);
+/// ```
+#[allow(clippy::module_name_repetitions)]
+pub fn write_typed<T, E>(data: &[T], mut write: impl FnMut(&[u8]) -> Result<(), E>) -> Result<(), E>
+where
+    T: bytemuck::NoUninit + LayoutFingerprint,
+{
+    write(&TypedHeader::for_slice(data).to_bytes())?;
+    write(bytemuck::cast_slice(data))
+}
+
+const fn write_bytes<const N: usize>(mut out: [u8; N], offset: usize, bytes: &[u8]) -> [u8; N] {
+    let mut i = 0;
+    while i < bytes.len() {
+        out[offset + i] = bytes[i];
+        i += 1;
+    }
+    out
+}
+
+/// Include a typed data file as a `&'static [T]`, checking its header against
+/// `T` at compile time.
+///
+/// The file must be in the self-describing format described by
+/// [`TypedHeader`], and `T` must implement both [`bytemuck::AnyBitPattern`]
+/// and [`LayoutFingerprint`]. A compiler error will be thrown if the header is
+/// invalid or does not match `T`: that is, if its element size, element type
+/// id (fingerprint), element count, endianness or checksum are wrong.
+///
+/// Otherwise, this behaves as [`include_slice`](crate::include_slice), returning only the payload.
+/// The path is interpreted by [`core::include_bytes`] and is
+/// host-platform-specific.
+///
+/// # Example
+///
+/// ```
+/// # use include_data::include_typed;
+/// # #[cfg(target_endian = "little")]
+/// static TYPED: &[u32] = include_typed!(u32, "../tests/test_data/typed_u32");
+/// ```
+///
+/// # Safety
+///
+/// This macro is safe. However, if used on a custom type, that type must
+/// implement [`bytemuck::AnyBitPattern`]. Implementing that trait has very
+/// strict safety requirements which must be observed.
+#[macro_export]
+#[allow(clippy::module_name_repetitions)]
+macro_rules! include_typed {
+    ($target_ty:ty, $file:expr $(,)?) => {{
+        const COUNT: usize = {
+            let file: &[u8] = ::core::include_bytes!($file);
+            let header = match $crate::TypedHeader::parse(file) {
+                ::core::result::Result::Ok(header) => header,
+                ::core::result::Result::Err(err) => panic!("{}", err.message()),
+            };
+            match header.validate::<$target_ty>($crate::TypedHeader::payload(file)) {
+                ::core::result::Result::Ok(()) => {}
+                ::core::result::Result::Err(err) => panic!("{}", err.message()),
+            }
+            header.count as usize
+        };
+
+        static ALIGNED: &$crate::AlignedAs<$target_ty, [u8]> = &$crate::AlignedAs {
+            _align: [],
+            bytes: *::core::include_bytes!($file),
+        };
+
+        let out_slice: &'static [$target_ty] = unsafe {
+            ::core::slice::from_raw_parts(
+                ALIGNED.bytes.as_ptr().add($crate::TypedHeader::LEN).cast(),
+                COUNT,
+            )
+        };
+
+        out_slice
+    }};
+}
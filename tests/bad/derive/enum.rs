@@ -0,0 +1,8 @@
+fn main() {}
+
+#[repr(C)]
+#[derive(Copy, Clone, include_data::IncludeData)]
+enum Enum {
+    A,
+    B,
+}
@@ -0,0 +1,5 @@
+fn main() {}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, include_data::IncludeData)]
+struct Generic<T>(T);
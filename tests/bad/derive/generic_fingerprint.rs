@@ -0,0 +1,5 @@
+fn main() {}
+
+#[repr(C)]
+#[derive(include_data::LayoutFingerprint)]
+struct Generic<T>(u8, T);
@@ -0,0 +1,8 @@
+fn main() {}
+
+// Layout of `repr(Rust)` types is unspecified
+#[derive(Copy, Clone, include_data::IncludeData)]
+struct NoRepr {
+    byte: u8,
+    other_byte: u8,
+}
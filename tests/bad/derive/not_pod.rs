@@ -0,0 +1,8 @@
+fn main() {}
+
+#[repr(C)]
+#[derive(Copy, Clone, include_data::IncludeData)]
+struct HasBool {
+    boolean: bool,
+    byte: u8,
+}
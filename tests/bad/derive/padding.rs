@@ -0,0 +1,9 @@
+fn main() {}
+
+// There is a padding byte between `byte` and `two_bytes`
+#[repr(C)]
+#[derive(Copy, Clone, include_data::IncludeData)]
+struct Padded {
+    byte: u8,
+    two_bytes: u16,
+}
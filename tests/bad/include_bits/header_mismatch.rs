@@ -0,0 +1,4 @@
+static BITS: include_data::BitSlice =
+    include_data::include_bits!("../../test_data/bits_header_u32", header = u32, len = 12);
+
+fn main() {}
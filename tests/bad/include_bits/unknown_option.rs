@@ -0,0 +1,3 @@
+static BITS: include_data::BitSlice = include_data::include_bits!("../../test_data/binary_4", order = middle);
+
+fn main() {}
@@ -0,0 +1,8 @@
+fn main() {}
+
+include_data::include_columns! {
+    struct Columns {
+        x: u32 = "../../test_data/binary_32",
+        y: u32 = "../../test_data/binary_64",
+    }
+}
@@ -0,0 +1,8 @@
+fn main() {}
+
+include_data::include_columns! {
+    struct Columns {
+        x: u8 = "../../test_data/binary_4",
+        y: bool = "../../test_data/binary_4",
+    }
+}
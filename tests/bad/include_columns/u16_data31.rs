@@ -0,0 +1,8 @@
+fn main() {}
+
+include_data::include_columns! {
+    struct Columns {
+        x: u8 = "../../test_data/binary_31",
+        y: u16 = "../../test_data/binary_31",
+    }
+}
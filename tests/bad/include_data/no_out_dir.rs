@@ -0,0 +1,3 @@
+fn main() {}
+
+static WRONG: [u8; 4] = include_data::include_data!("binary_4", from = out_dir);
@@ -0,0 +1,5 @@
+use include_data::Q15;
+
+static COEFFICIENTS: &[Q15] = include_data::include_decimals!(Q15, "../../test_data/decimal_invalid");
+
+fn main() {}
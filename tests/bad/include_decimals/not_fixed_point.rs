@@ -0,0 +1,3 @@
+static COEFFICIENTS: &[f32] = include_data::include_decimals!(f32, "../../test_data/q15_decimal");
+
+fn main() {}
@@ -0,0 +1,6 @@
+use include_data::Q7;
+
+// 0.999969482421875 is a valid `Q15`, but rounds up to 1.0 as a `Q7`.
+static COEFFICIENTS: &[Q7] = include_data::include_decimals!(Q7, "../../test_data/q15_decimal");
+
+fn main() {}
@@ -0,0 +1,3 @@
+fn main() {}
+
+static WRONG: bool = include_data::include_data_or_default!(bool, "non-existent");
@@ -0,0 +1,3 @@
+fn main() {}
+
+static WRONG: &[u32] = include_data::include_slice_or!(u32, "non-existent", &[1_u16]);
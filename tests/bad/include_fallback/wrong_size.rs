@@ -0,0 +1,4 @@
+fn main() {}
+
+// The file exists, so is still size checked
+static WRONG: &[u32] = include_data::include_slice_or!(u32, "../../../../tests/test_data/binary_31", &[]);
@@ -0,0 +1,3 @@
+fn main() {}
+
+include_data::include_glob!(enum Missing: u8 = "tests/test_data/non-existent_*");
@@ -0,0 +1,4 @@
+fn main() {}
+
+// Non-AnyBitPattern type
+include_data::include_glob!(enum Binaries: bool = "../../../../tests/test_data/binary_2");
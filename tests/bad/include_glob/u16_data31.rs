@@ -0,0 +1,5 @@
+fn main() {}
+
+// Trybuild runs from `target/tests/trybuild/include_data`, hence the path.
+// binary_31 can't make a u16 slice
+include_data::include_glob!(enum Binaries: u16 = "../../../../tests/test_data/binary_3*");
@@ -0,0 +1,3 @@
+static WEIGHTS: &[half::f16] = include_data::include_f16s_be!("../../test_data/binary_31");
+
+fn main() {}
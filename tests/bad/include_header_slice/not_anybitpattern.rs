@@ -0,0 +1,3 @@
+static TABLE: (&bool, &[u8]) = include_data::include_header_slice!(bool, u8, "../../test_data/binary_4");
+
+fn main() {}
@@ -0,0 +1,3 @@
+static TABLE: (&[u8; 8], &[u8]) = include_data::include_header_slice!([u8; 8], u8, "../../test_data/binary_4");
+
+fn main() {}
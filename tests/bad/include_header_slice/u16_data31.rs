@@ -0,0 +1,3 @@
+static TABLE: (&u16, &[u16]) = include_data::include_header_slice!(u16, u16, "../../test_data/binary_31");
+
+fn main() {}
@@ -0,0 +1,13 @@
+#[repr(C)]
+#[derive(Copy, Clone)]
+struct Header {
+    count: u32,
+}
+
+unsafe impl bytemuck::Zeroable for Header {}
+unsafe impl bytemuck::AnyBitPattern for Header {}
+
+static TABLE: (&Header, &[u32]) =
+    include_data::include_header_slice!(Header, u32, "../../test_data/binary_32", count = count);
+
+fn main() {}
@@ -0,0 +1,4 @@
+static WORDS: include_data::HotSlice<u32> =
+    include_data::include_slice!(u32, "../../test_data/binary_31", hot);
+
+fn main() {}
@@ -0,0 +1,4 @@
+static VALUE: include_data::HotData<u32> =
+    include_data::include_data!("../../test_data/binary_8", hot);
+
+fn main() {}
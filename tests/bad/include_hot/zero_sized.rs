@@ -0,0 +1,3 @@
+static UNITS: include_data::HotSlice<()> = include_data::HotSlice::new("units", &[]);
+
+fn main() {}
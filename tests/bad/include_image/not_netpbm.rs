@@ -0,0 +1,4 @@
+static IMAGE: embedded_graphics::image::ImageRaw<embedded_graphics::pixelcolor::Gray8> =
+    include_data::include_netpbm!(embedded_graphics::pixelcolor::Gray8, "../../test_data/binary_32");
+
+fn main() {}
@@ -0,0 +1,5 @@
+// 32 bytes is not a whole number of rows of 3 `Rgb888` pixels.
+static IMAGE: embedded_graphics::image::ImageRaw<embedded_graphics::pixelcolor::Rgb888> =
+    include_data::include_image_raw!(embedded_graphics::pixelcolor::Rgb888, 3, "../../test_data/binary_32");
+
+fn main() {}
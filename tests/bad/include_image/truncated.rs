@@ -0,0 +1,5 @@
+// The header gives 3 * 2 pixels, but the file holds only 5.
+static IMAGE: embedded_graphics::image::ImageRaw<embedded_graphics::pixelcolor::Rgb565> =
+    include_data::include_netpbm!(embedded_graphics::pixelcolor::Rgb565, "../../test_data/image_truncated.ppm");
+
+fn main() {}
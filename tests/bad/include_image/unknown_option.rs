@@ -0,0 +1,4 @@
+static IMAGE: embedded_graphics::image::ImageRaw<embedded_graphics::pixelcolor::Rgb565> =
+    include_data::include_image_raw!(embedded_graphics::pixelcolor::Rgb565, 4, "../../test_data/binary_32", endian = middle);
+
+fn main() {}
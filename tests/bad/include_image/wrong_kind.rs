@@ -0,0 +1,5 @@
+// A PPM image has color channels, not a gray level.
+static IMAGE: embedded_graphics::image::ImageRaw<embedded_graphics::pixelcolor::Gray8> =
+    include_data::include_netpbm!(embedded_graphics::pixelcolor::Gray8, "../../test_data/image_3x2.ppm");
+
+fn main() {}
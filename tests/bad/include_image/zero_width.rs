@@ -0,0 +1,4 @@
+static IMAGE: embedded_graphics::image::ImageRaw<embedded_graphics::pixelcolor::Gray8> =
+    include_data::include_image_raw!(embedded_graphics::pixelcolor::Gray8, 0, "../../test_data/binary_32");
+
+fn main() {}
@@ -0,0 +1,3 @@
+static LINES: &[&str] = include_data::include_lines!("../../test_data/lines", skip_empty);
+
+fn main() {}
@@ -0,0 +1,4 @@
+static MAP: include_data::StaticMap<u16, u16> =
+    include_data::include_map!(u16, u16, "../../test_data/map_duplicate");
+
+fn main() {}
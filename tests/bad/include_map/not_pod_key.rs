@@ -0,0 +1,4 @@
+static MAP: include_data::StaticMap<bool, u8> =
+    include_data::include_map!(bool, u8, "../../test_data/binary_2");
+
+fn main() {}
@@ -0,0 +1,4 @@
+static MAP: include_data::StaticMap<u16, u8> =
+    include_data::include_map!(u16, u8, "../../test_data/binary_32");
+
+fn main() {}
@@ -0,0 +1,3 @@
+static ROWS: &[[bool; 4]] = include_data::include_matrix!(bool, cols = 4, "../../test_data/binary_32");
+
+fn main() {}
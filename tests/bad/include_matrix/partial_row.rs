@@ -0,0 +1,3 @@
+static ROWS: &[[u8; 3]] = include_data::include_matrix!(u8, cols = 3, "../../test_data/binary_32");
+
+fn main() {}
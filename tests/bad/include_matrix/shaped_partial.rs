@@ -0,0 +1,3 @@
+static VOLUME: &[[[u16; 3]; 2]] = include_data::include_shaped!(u16, [_, 2, 3], "../../test_data/binary_32");
+
+fn main() {}
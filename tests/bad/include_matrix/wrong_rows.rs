@@ -0,0 +1,3 @@
+const ROWS: &[[u8; 8]] = include_data::include_matrix!(u8, rows = 3, cols = 8, "../../test_data/binary_32");
+
+fn main() {}
@@ -0,0 +1,4 @@
+static DATA: include_data::StaticArray<u8, 1> =
+    include_data::include_npy!(u8, "../../test_data/binary_32");
+
+fn main() {}
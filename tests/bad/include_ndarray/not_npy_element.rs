@@ -0,0 +1,5 @@
+// Only primitive numbers have a NumPy data type.
+static DATA: include_data::StaticArray<[u8; 4], 2> =
+    include_data::include_npy!([u8; 4], "../../test_data/matrix_f32_2x3.npy");
+
+fn main() {}
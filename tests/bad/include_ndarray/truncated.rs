@@ -0,0 +1,5 @@
+// The header gives a shape of (2, 3), but the file holds only 5 `f32`s.
+static MATRIX: include_data::StaticArray<f32, 2> =
+    include_data::include_npy!(f32, "../../test_data/truncated_f32_2x3.npy");
+
+fn main() {}
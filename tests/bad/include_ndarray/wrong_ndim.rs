@@ -0,0 +1,5 @@
+// The file holds a two-dimensional array.
+static MATRIX: include_data::StaticArray<f32, 1> =
+    include_data::include_npy!(f32, "../../test_data/matrix_f32_2x3.npy");
+
+fn main() {}
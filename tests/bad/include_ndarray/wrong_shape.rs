@@ -0,0 +1,5 @@
+// 32 bytes is 16 `u16`s, not 3 * 5.
+static GRID: include_data::StaticArray<u16, 2> =
+    include_data::include_ndarray!(u16, [3, 5], "../../test_data/binary_32");
+
+fn main() {}
@@ -0,0 +1,5 @@
+// The file holds `<f4` data.
+static MATRIX: include_data::StaticArray<f64, 2> =
+    include_data::include_npy!(f64, "../../test_data/matrix_f32_2x3.npy");
+
+fn main() {}
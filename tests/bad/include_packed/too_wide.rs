@@ -0,0 +1,4 @@
+static SAMPLES: include_data::PackedSlice<u8> =
+    include_data::include_packed!(u8, bits = 12, "../../test_data/binary_12");
+
+fn main() {}
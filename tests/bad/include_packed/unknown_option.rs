@@ -0,0 +1,4 @@
+static SAMPLES: include_data::PackedSlice<u16> =
+    include_data::include_u12s!("../../test_data/binary_12", endian = middle);
+
+fn main() {}
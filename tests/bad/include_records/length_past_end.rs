@@ -0,0 +1,3 @@
+static RECORDS: include_data::Records = include_data::include_records!(prefix = u32, "../../test_data/binary_8");
+
+fn main() {}
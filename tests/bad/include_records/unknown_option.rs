@@ -0,0 +1,4 @@
+static RECORDS: include_data::Records =
+    include_data::include_records!(terminator = b'\0', "../../test_data/records_nul", sorted);
+
+fn main() {}
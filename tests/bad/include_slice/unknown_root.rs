@@ -0,0 +1,3 @@
+fn main() {}
+
+static WRONG: &[u8] = include_data::include_slice!(u8, "test_data/binary_4", from = crate_root);
@@ -0,0 +1,3 @@
+fn main() {}
+
+static WRONG: &[u8] = include_data::include_slice_concat!(u8, []);
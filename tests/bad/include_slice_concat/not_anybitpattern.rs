@@ -0,0 +1,3 @@
+fn main() {}
+
+static WRONG: &[bool] = include_data::include_slice_concat!(bool, ["../../test_data/binary_4"]);
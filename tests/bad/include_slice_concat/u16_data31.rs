@@ -0,0 +1,7 @@
+fn main() {}
+
+// Total size is divisible by 2, but elements would straddle files
+static WRONG: &[u16] = include_data::include_slice_concat!(
+    u16,
+    ["../../test_data/binary_31", "../../test_data/binary_31"],
+);
@@ -0,0 +1,4 @@
+static FLOATS: include_data::SortedSlice<f32> =
+    include_data::include_sorted_slice!(f32, "../../test_data/nan_f32");
+
+fn main() {}
@@ -0,0 +1,4 @@
+static PAIRS: include_data::SortedSlice<(u8, u8)> =
+    include_data::include_sorted_slice!((u8, u8), "../../test_data/binary_32");
+
+fn main() {}
@@ -0,0 +1,4 @@
+static WORDS: include_data::SortedSlice<u16> =
+    include_data::include_sorted_slice!(u16, "../../test_data/unsorted_u16");
+
+fn main() {}
@@ -0,0 +1,19 @@
+#[derive(Clone, Copy, PartialEq, PartialOrd)]
+#[repr(transparent)]
+struct Word(u16);
+
+unsafe impl bytemuck::Zeroable for Word {}
+unsafe impl bytemuck::AnyBitPattern for Word {}
+
+unsafe impl include_data::ConstOrd for Word {
+    const LAYOUT: include_data::OrdLayout = include_data::OrdLayout {
+        kind: include_data::OrdKind::Unsigned,
+        size: 8,
+        count: 1,
+    };
+}
+
+static WORDS: include_data::SortedSlice<Word> =
+    include_data::include_sorted_slice!(Word, "../../test_data/sorted_i16");
+
+fn main() {}
@@ -0,0 +1,3 @@
+fn main() {}
+
+static WRONG: &[bool] = include_data::include_typed!(bool, "../../test_data/typed_u32");
@@ -0,0 +1,3 @@
+fn main() {}
+
+static WRONG: &[u32] = include_data::include_typed!(u32, "../../test_data/binary_4");
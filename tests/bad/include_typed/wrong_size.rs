@@ -0,0 +1,3 @@
+fn main() {}
+
+static WRONG: &[u16] = include_data::include_typed!(u16, "../../test_data/typed_u32");
@@ -0,0 +1,3 @@
+fn main() {}
+
+static WRONG: &[i32] = include_data::include_typed!(i32, "../../test_data/typed_u32");
@@ -0,0 +1,5 @@
+fn main() {}
+
+// Not long enough for the header
+static SHORT: u32 =
+    unsafe { include_data::include_unsafe!("../../test_data/binary_4", fingerprint_header) };
@@ -0,0 +1,7 @@
+use include_data::LayoutFingerprint;
+
+fn main() {}
+
+static WRONG: u32 = unsafe {
+    include_data::include_unsafe!("../../test_data/binary_4", fingerprint = i32::FINGERPRINT)
+};
@@ -0,0 +1,5 @@
+fn main() {}
+
+// The first 8 bytes are not the fingerprint of `u32`
+static WRONG: u32 =
+    unsafe { include_data::include_unsafe!("../../test_data/binary_12", fingerprint_header) };
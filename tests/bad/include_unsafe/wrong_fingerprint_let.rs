@@ -0,0 +1,8 @@
+use include_data::LayoutFingerprint;
+
+fn main() {
+    let wrong: u32 = unsafe {
+        include_data::include_unsafe!("../../test_data/binary_4", fingerprint = i32::FINGERPRINT)
+    };
+    let _ = wrong;
+}
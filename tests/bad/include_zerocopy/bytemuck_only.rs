@@ -0,0 +1,11 @@
+// Without `via = zerocopy`, the `bytemuck` traits are required.
+#[derive(zerocopy::FromBytes, zerocopy::Immutable)]
+#[repr(C)]
+struct Pair {
+    first: u16,
+    second: u16,
+}
+
+static PAIR: Pair = include_data::include_data!("../../test_data/file_exactly_4_bytes_long");
+
+fn main() {}
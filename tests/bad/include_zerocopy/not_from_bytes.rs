@@ -0,0 +1,11 @@
+// `bool` is not valid for every bit pattern.
+#[derive(zerocopy::Immutable)]
+#[repr(C)]
+struct Flags {
+    enabled: bool,
+    values: [u8; 3],
+}
+
+static FLAGS: Flags = include_data::include_data!("../../test_data/file_exactly_4_bytes_long", via = zerocopy);
+
+fn main() {}
@@ -0,0 +1,5 @@
+// Slices of types with interior mutability cannot be shared as statics.
+static CELLS: &[core::cell::Cell<u32>] =
+    include_data::include_slice!(core::cell::Cell<u32>, "../../test_data/binary_32", via = zerocopy);
+
+fn main() {}
@@ -0,0 +1,3 @@
+static DATA: &[u32] = include_data::include_slice!(u32, "../../test_data/binary_32", via = serde);
+
+fn main() {}
@@ -0,0 +1,5 @@
+// `nalgebra` types are `Pod` only if their scalar type is.
+static MATRICES: &[nalgebra::Matrix2<bool>] =
+    include_data::include_slice!(nalgebra::Matrix2<bool>, "../../test_data/binary_64");
+
+fn main() {}
@@ -0,0 +1,4 @@
+// `BVec3A` is a SIMD mask, not every bit pattern of which is valid.
+static MASKS: &[glam::BVec3A] = include_data::include_slice!(glam::BVec3A, "../../test_data/binary_64");
+
+fn main() {}
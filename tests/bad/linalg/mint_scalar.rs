@@ -0,0 +1,3 @@
+static MASKS: &[mint::Vector4<bool>] = include_data::include_mint!(mint::Vector4<bool>, "../../test_data/binary_64");
+
+fn main() {}
@@ -0,0 +1,4 @@
+// `Vec3` has no padding, so should be included with `include_slice`.
+static POINTS: &[glam::Vec3] = include_data::include_padded!(glam::Vec3, "../../test_data/vec3_f32");
+
+fn main() {}
@@ -0,0 +1,4 @@
+// 32 bytes is not a whole number of packed `[f32; 3]`s.
+static POINTS: &[glam::Vec3A] = include_data::include_padded!(glam::Vec3A, "../../test_data/binary_32");
+
+fn main() {}
@@ -0,0 +1,79 @@
+use include_data::{include_data, include_slice, IncludeData};
+
+#[test]
+fn derive_struct() {
+    #[repr(C)]
+    #[derive(Copy, Clone, IncludeData)]
+    struct Foo {
+        integer: u16,
+        pair: [u8; 2],
+    }
+
+    static FOO_DATA: Foo = include_data!("test_data/binary_4");
+
+    if cfg!(target_endian = "little") {
+        assert_eq!(FOO_DATA.integer, 0x0100);
+    } else {
+        assert_eq!(FOO_DATA.integer, 0x0001);
+    }
+    assert_eq!(FOO_DATA.pair, [0x02, 0x03]);
+}
+
+#[test]
+fn derive_tuple_struct() {
+    #[repr(transparent)]
+    #[derive(Copy, Clone, IncludeData)]
+    struct Wrapper(u32);
+
+    static WRAPPERS: &[Wrapper] = include_slice!(Wrapper, "test_data/binary_32");
+
+    assert_eq!(WRAPPERS.as_ptr().align_offset(4), 0);
+    assert_eq!(WRAPPERS.len(), 8);
+    assert_eq!(WRAPPERS[1].0, u32::from_ne_bytes([4, 5, 6, 7]));
+}
+
+#[test]
+fn derive_packed() {
+    #[repr(C, packed)]
+    #[derive(Copy, Clone, IncludeData)]
+    struct Packed {
+        byte: u8,
+        word: u32,
+        rest: [u8; 3],
+    }
+
+    const PACKED: Packed = include_data!("test_data/binary_8");
+
+    let word = PACKED.word;
+    assert_eq!(PACKED.byte, 0);
+    assert_eq!(word, u32::from_ne_bytes([1, 2, 3, 4]));
+    assert_eq!(PACKED.rest, [5, 6, 7]);
+}
+
+#[test]
+fn derive_nested() {
+    #[repr(C)]
+    #[derive(Copy, Clone, IncludeData)]
+    struct Inner {
+        a: u8,
+        b: u8,
+    }
+
+    #[repr(C)]
+    #[derive(Copy, Clone, IncludeData)]
+    struct Outer {
+        inners: [Inner; 2],
+        c: u32,
+    }
+
+    static OUTER: Outer = include_data!("test_data/binary_8");
+
+    assert_eq!(OUTER.inners[1].a, 2);
+    assert_eq!(OUTER.inners[1].b, 3);
+}
+
+#[test]
+fn bad_use() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/bad/derive/*.rs");
+}
@@ -0,0 +1,52 @@
+use include_data::{FingerprintHasher, LayoutFingerprint};
+
+#[test]
+fn primitives() {
+    assert_ne!(u32::FINGERPRINT, i32::FINGERPRINT);
+    assert_ne!(u32::FINGERPRINT, f32::FINGERPRINT);
+    assert_ne!(u8::FINGERPRINT, bool::FINGERPRINT);
+    assert_ne!(<[u16; 2]>::FINGERPRINT, <[u8; 4]>::FINGERPRINT);
+    assert_ne!(<[u16; 2]>::FINGERPRINT, <[u16; 3]>::FINGERPRINT);
+}
+
+#[test]
+fn hasher() {
+    // Known FNV-1a test vectors
+    assert_eq!(FingerprintHasher::new().finish(), 0xcbf2_9ce4_8422_2325);
+    assert_eq!(
+        FingerprintHasher::new().write(b"a").finish(),
+        0xaf63_dc4c_8601_ec8c,
+    );
+}
+
+#[test]
+fn derive() {
+    #[repr(C)]
+    #[derive(LayoutFingerprint)]
+    struct Foo {
+        a: u8,
+        b: u16,
+    }
+
+    #[repr(C)]
+    #[derive(LayoutFingerprint)]
+    struct RenamedFoo {
+        first: u8,
+        second: u16,
+    }
+
+    #[repr(C, packed)]
+    #[derive(LayoutFingerprint)]
+    struct PackedFoo {
+        a: u8,
+        b: u16,
+    }
+
+    #[repr(C)]
+    #[derive(LayoutFingerprint)]
+    struct Tuple(u8, u16);
+
+    assert_eq!(Foo::FINGERPRINT, RenamedFoo::FINGERPRINT);
+    assert_eq!(Foo::FINGERPRINT, Tuple::FINGERPRINT);
+    assert_ne!(Foo::FINGERPRINT, PackedFoo::FINGERPRINT);
+}
@@ -0,0 +1,8 @@
+use include_data::LayoutFingerprint;
+
+fn main() {
+    let value: u32 = unsafe {
+        include_data::include_unsafe!("../../test_data/binary_4", fingerprint = u32::FINGERPRINT)
+    };
+    assert_eq!(value, u32::from_ne_bytes([0, 1, 2, 3]));
+}
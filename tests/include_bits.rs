@@ -0,0 +1,75 @@
+use include_data::{include_bits, BitOrder, BitSlice};
+
+#[test]
+fn bit_orders() {
+    static LSB: BitSlice = include_bits!("test_data/binary_4");
+    const MSB: BitSlice = include_bits!("test_data/binary_4", order = msb);
+
+    assert_eq!(LSB.len(), 32);
+    assert_eq!(LSB.order(), BitOrder::LsbFirst);
+    assert_eq!(LSB.get(8), Some(true));
+    assert_eq!(LSB.get(15), Some(false));
+    assert_eq!(MSB.get(8), Some(false));
+    assert_eq!(MSB.get(15), Some(true));
+    assert_eq!(LSB.get(32), None);
+
+    // Bytes 0, 1, 2 and 3.
+    assert_eq!(LSB.count_ones(), 4);
+    assert_eq!(MSB.count_zeros(), 28);
+    assert_eq!(LSB.iter().filter(|bit| *bit).count(), 4);
+    let forwards: Vec<bool> = LSB.iter().collect();
+    assert!(LSB.iter().rev().eq(forwards.into_iter().rev()));
+}
+
+#[test]
+fn lengths() {
+    static EXACT: BitSlice = include_bits!("test_data/binary_4", len = 25, order = lsb);
+    static EXPR: BitSlice = include_bits!("test_data/binary_4", len = (8 * 3 + 1));
+
+    assert_eq!(EXACT.len(), 25);
+    assert_eq!(EXPR.len(), 25);
+    assert_eq!(EXACT.count_ones(), 3);
+}
+
+// The fixture has a little-endian length header, which is checked at compile
+// time.
+#[cfg(target_endian = "little")]
+#[test]
+fn header() {
+    static HEADER: BitSlice = include_bits!("test_data/bits_header_u32", header = u32);
+    static BOTH: BitSlice = include_bits!("test_data/bits_header_u32", header = u32, len = 13);
+
+    assert_eq!(HEADER.len(), 13);
+    assert_eq!(BOTH.len(), 13);
+    assert_eq!(HEADER.as_bytes(), [0b1111_0001, 0b0001_0110]);
+    let bits: Vec<bool> = HEADER.iter().collect();
+    assert_eq!(
+        bits,
+        [true, false, false, false, true, true, true, true, false, true, true, false, true]
+    );
+    assert_eq!(HEADER.count_ones(), 8);
+}
+
+#[test]
+fn large() {
+    static ALL: BitSlice = include_bits!("test_data/binary_64");
+    let expected: u32 = (0..64_u8).map(u8::count_ones).sum();
+    assert_eq!(ALL.count_ones(), expected as usize);
+    assert_eq!(ALL.iter().len(), 512);
+}
+
+#[test]
+fn runtime() {
+    static BYTES: [u8; 2] = [0xff, 0x01];
+    let bits = BitSlice::with_len(&BYTES, 9, BitOrder::LsbFirst);
+    assert_eq!(bits.count_ones(), 9);
+    assert_eq!(format!("{bits:?}"), "BitSlice[111111111]");
+}
+
+// `header_mismatch` depends on the little-endian header of its fixture.
+#[cfg(target_endian = "little")]
+#[test]
+fn bad_use() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/bad/include_bits/*.rs");
+}
@@ -0,0 +1,47 @@
+#![allow(clippy::modulo_one)]
+
+use include_data::include_columns;
+
+include_columns! {
+    /// Columns of different types.
+    #[derive(Debug)]
+    pub struct Columns {
+        /// A `u32` column.
+        pub wide: u32 = "test_data/binary_32",
+        pub(crate) narrow: u8 = "test_data/binary_8",
+        arrays: [i8; 8] = "test_data/binary_64",
+    }
+}
+
+include_columns! {
+    struct Single {
+        only: u16 = "test_data/binary_4"
+    }
+}
+
+#[test]
+fn columns() {
+    static WIDE: &[u32] = include_data::include_u32s!("test_data/binary_32");
+
+    let columns = Columns::get();
+    assert_eq!(Columns::LEN, 8);
+    assert_eq!(columns.len(), 8);
+    assert!(!columns.is_empty());
+
+    assert_eq!(columns.wide, WIDE);
+    assert_eq!(columns.wide.as_ptr().align_offset(4), 0);
+    assert_eq!(columns.narrow, [0, 1, 2, 3, 4, 5, 6, 7]);
+    assert_eq!(columns.arrays[7], [56, 57, 58, 59, 60, 61, 62, 63]);
+}
+
+#[test]
+fn single_column() {
+    assert_eq!(Single::LEN, 2);
+    assert_eq!(Single::get().only.len(), 2);
+}
+
+#[test]
+fn bad_use() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/bad/include_columns/*.rs");
+}
@@ -79,3 +79,14 @@ fn bad_use() {
     let t = trybuild::TestCases::new();
     t.compile_fail("tests/bad/include_data/*.rs");
 }
+
+#[test]
+fn path_roots() {
+    const MANIFEST: u32 = include_data!("tests/test_data/binary_4", from = manifest);
+    const WORKSPACE: u32 = include_data!("tests/test_data/binary_4", from = workspace);
+    const ENV: u32 = include_data!("tests/test_data/binary_4", from = env("CARGO_MANIFEST_DIR"));
+
+    assert_eq!(MANIFEST, u32::from_ne_bytes([0, 1, 2, 3]));
+    assert_eq!(WORKSPACE, MANIFEST);
+    assert_eq!(ENV, MANIFEST);
+}
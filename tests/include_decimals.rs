@@ -0,0 +1,75 @@
+#![allow(clippy::modulo_one)]
+
+use include_data::{
+    include_decimals, include_q15s, include_q31s, include_q7s, include_sorted_slice, SortedSlice,
+    Q15, Q31, Q7,
+};
+
+#[test]
+fn decimals() {
+    static Q15S: &[Q15] = include_decimals!(Q15, "test_data/q15_decimal");
+    static Q31S: &[Q31] = include_decimals!(Q31, "test_data/q15_decimal");
+
+    assert!(Q15S
+        .iter()
+        .map(|value| value.to_bits())
+        .eq([0x4000, -0x2000, -0x8000, 0x7fff, 0x1000]));
+    assert!(Q31S.iter().map(|value| value.to_bits()).eq([
+        0x4000_0000,
+        -0x2000_0000,
+        -0x8000_0000,
+        0x7fff_0000,
+        0x1000_0000
+    ]));
+    assert_eq!(Q15S[2], Q15::MIN);
+}
+
+#[test]
+fn rounding() {
+    const Q7S: &[Q7] = include_decimals!(Q7, "test_data/q7_rounding");
+
+    assert!(Q7S
+        .iter()
+        .map(|value| value.to_bits())
+        .eq([1, -1, 2, 127, -115, 0]));
+}
+
+#[test]
+fn binary() {
+    static Q15S: &[Q15] = include_q15s!("test_data/binary_8");
+    static Q31S: &[Q31] = include_q31s!("test_data/binary_8");
+    static Q7S: &[Q7] = include_q7s!("test_data/binary_8");
+    static BYTES: &[u8] = include_bytes!("test_data/binary_8");
+
+    assert!(Q15S.iter().map(|value| value.to_bits()).eq(BYTES
+        .chunks(2)
+        .map(|pair| i16::from_ne_bytes([pair[0], pair[1]]))));
+    assert_eq!(Q31S.len(), 2);
+    assert_eq!(Q7S[7], Q7::from_bits(7));
+}
+
+#[test]
+fn conversions() {
+    assert_eq!(Q15::from_f64(0.5).to_bits(), 0x4000);
+    assert_eq!(Q15::from_f64(-1.5), Q15::MIN);
+    assert_eq!(Q15::from_f64(1.0), Q15::MAX);
+    assert_eq!(Q15::from_f64(f64::NAN), Q15::ZERO);
+    assert_eq!(Q7::from_f64(1.5 / 128.0).to_bits(), 2);
+    assert_eq!(Q7::from_f64(-1.5 / 128.0).to_bits(), -2);
+    assert_eq!(Q31::from_bits(-0x4000_0000).to_f32(), -0.5);
+    assert_eq!(f64::from(Q15::MAX), 1.0 - 1.0 / 32768.0);
+    assert_eq!(format!("{} {:?}", Q7::from_bits(32), Q7::MIN), "0.25 -1.0");
+}
+
+#[test]
+fn sorted() {
+    static SORTED: SortedSlice<Q15> = include_sorted_slice!(Q15, "test_data/sorted_i16");
+
+    assert!(SORTED.windows(2).all(|pair| pair[0] <= pair[1]));
+}
+
+#[test]
+fn bad_use() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/bad/include_decimals/*.rs");
+}
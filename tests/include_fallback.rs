@@ -0,0 +1,97 @@
+use include_data::{file_exists, include_data_or, include_data_or_default, include_slice_or};
+
+#[test]
+fn file_exists() {
+    let found = file_exists!("tests/test_data/binary_32");
+    let missing = file_exists!("tests/test_data/non-existent");
+    let directory = file_exists!("tests/test_data");
+
+    assert!(found);
+    assert!(!missing);
+    assert!(!directory);
+}
+
+#[test]
+fn slice_found() {
+    static FOUND: &[u32] = include_slice_or!(u32, "tests/test_data/binary_32", &[]);
+    static PLAIN: &[u32] = include_data::include_u32s!("test_data/binary_32");
+
+    assert_eq!(FOUND.as_ptr().align_offset(4), 0);
+    assert_eq!(FOUND, PLAIN);
+}
+
+#[test]
+fn slice_missing() {
+    static MISSING: &[u32] = include_slice_or!(u32, "tests/test_data/non-existent", &[1, 2]);
+    static EMPTY: &[f64] = include_slice_or!(f64, "tests/test_data/non-existent", &[]);
+
+    assert_eq!(MISSING, [1, 2]);
+    assert!(EMPTY.is_empty());
+}
+
+#[test]
+fn data_or() {
+    const FOUND: u32 = include_data_or!("tests/test_data/binary_4", 7);
+    const MISSING: u32 = include_data_or!("tests/test_data/non-existent", 7);
+    static STATIC: [u16; 2] = include_data_or!("tests/test_data/binary_4", [0; 2]);
+
+    assert_eq!(FOUND, u32::from_ne_bytes([0, 1, 2, 3]));
+    assert_eq!(MISSING, 7);
+    assert_eq!(
+        STATIC,
+        [u16::from_ne_bytes([0, 1]), u16::from_ne_bytes([2, 3])]
+    );
+}
+
+#[test]
+fn data_or_default() {
+    #[repr(C)]
+    #[derive(Clone, Copy, Debug, PartialEq, include_data::IncludeData)]
+    struct Calibration {
+        gain: f32,
+        offset: i32,
+    }
+
+    const FOUND: Calibration = include_data_or_default!(Calibration, "tests/test_data/binary_8");
+    const MISSING: Calibration =
+        include_data_or_default!(Calibration, "tests/test_data/non-existent");
+
+    assert_eq!(FOUND.offset, i32::from_ne_bytes([4, 5, 6, 7]));
+    assert_eq!(
+        MISSING,
+        Calibration {
+            gain: 0.0,
+            offset: 0
+        }
+    );
+}
+
+#[test]
+fn found_flag() {
+    const SLICE_FOUND: (&[u32], bool) =
+        include_slice_or!(u32, "tests/test_data/binary_32", &[], found);
+    const SLICE_MISSING: (&[u32], bool) =
+        include_slice_or!(u32, "tests/test_data/non-existent", &[1, 2], found);
+    static DATA_FOUND: (u32, bool) = include_data_or!("tests/test_data/binary_4", 7, found);
+    const DATA_MISSING: (u32, bool) = include_data_or!("tests/test_data/non-existent", 7, found);
+    const DEFAULT_FOUND: ([u16; 2], bool) =
+        include_data_or_default!([u16; 2], "tests/test_data/binary_4", found);
+    const DEFAULT_MISSING: ([u16; 2], bool) =
+        include_data_or_default!([u16; 2], "tests/test_data/non-existent", found);
+
+    static SLICE: &[u32] = SLICE_FOUND.0;
+
+    assert_eq!(SLICE, include_data::include_u32s!("test_data/binary_32"));
+    assert!(SLICE_FOUND.1);
+    assert_eq!(SLICE_MISSING, (&[1, 2][..], false));
+    assert_eq!(DATA_FOUND, (u32::from_ne_bytes([0, 1, 2, 3]), true));
+    assert_eq!(DATA_MISSING, (7, false));
+    assert!(DEFAULT_FOUND.1);
+    assert_eq!(DEFAULT_MISSING, ([0, 0], false));
+}
+
+#[test]
+fn bad_use() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/bad/include_fallback/*.rs");
+}
@@ -0,0 +1,66 @@
+use include_data::include_glob;
+
+include_glob! {
+    /// All of the incrementing binary test files.
+    enum Binaries: u8 = "tests/test_data/binary_*";
+}
+
+include_glob!(pub(crate) enum Utf32Files: u32 = "tests/test_data/*utf*");
+
+#[test]
+fn variants() {
+    assert_eq!(
+        Binaries::ALL,
+        [
+            Binaries::Binary12,
+            Binaries::Binary2,
+            Binaries::Binary31,
+            Binaries::Binary32,
+            Binaries::Binary4,
+            Binaries::Binary64,
+            Binaries::Binary8,
+        ],
+    );
+    assert_eq!(
+        Utf32Files::ALL,
+        [Utf32Files::LoremIpsumUtf32, Utf32Files::SomeUtf32File],
+    );
+}
+
+#[test]
+fn get() {
+    for binary in Binaries::ALL {
+        let expected_len: usize = binary.path()["tests/test_data/binary_".len()..]
+            .parse()
+            .unwrap();
+        let bytes = binary.get();
+
+        assert_eq!(bytes.len(), expected_len);
+        for (i, byte) in bytes.iter().enumerate() {
+            assert_eq!(usize::from(*byte), i);
+        }
+    }
+}
+
+#[test]
+fn get_const() {
+    const LOREM_IPSUM: &[u32] = Utf32Files::LoremIpsumUtf32.get();
+
+    assert_eq!(LOREM_IPSUM.as_ptr().align_offset(4), 0);
+    assert_eq!(LOREM_IPSUM.len(), 13);
+    assert_eq!(LOREM_IPSUM[1], 'L' as u32);
+}
+
+#[test]
+fn path() {
+    assert_eq!(
+        Utf32Files::SomeUtf32File.path(),
+        "tests/test_data/some_utf-32_file",
+    );
+}
+
+#[test]
+fn bad_use() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/bad/include_glob/*.rs");
+}
@@ -0,0 +1,52 @@
+use half::{bf16, f16};
+use include_data::{
+    include_bf16s, include_bf16s_be, include_bf16s_le, include_f16s, include_f16s_be,
+    include_f16s_le,
+};
+
+const F16_VALUES: [f32; 4] = [1.0, -2.0, 0.5, 65504.0];
+const BF16_VALUES: [f32; 4] = [1.0, -2.0, 0.5, 3.0];
+
+#[test]
+fn f16_endianness() {
+    static LITTLE: &[f16] = include_f16s_le!("test_data/f16_le");
+    static BIG: &[f16] = include_f16s_be!("test_data/f16_be");
+    const BIG_CONST: &[f16] = include_f16s_be!("tests/test_data/f16_be", from = manifest);
+
+    for slice in [LITTLE, BIG, BIG_CONST] {
+        assert_eq!(slice.len(), 4);
+        assert!(slice.iter().map(|value| value.to_f32()).eq(F16_VALUES));
+    }
+}
+
+#[test]
+fn bf16_endianness() {
+    static LITTLE: &[bf16] = include_bf16s_le!("test_data/bf16_le");
+    static BIG: &[bf16] = include_bf16s_be!("test_data/bf16_be");
+
+    for slice in [LITTLE, BIG] {
+        assert!(slice.iter().map(|value| value.to_f32()).eq(BF16_VALUES));
+    }
+}
+
+#[test]
+fn native() {
+    static F16S: &[f16] = include_f16s!("test_data/binary_8");
+    static BF16S: &[bf16] = include_bf16s!("test_data/binary_8");
+    static BYTES: &[u8] = include_bytes!("test_data/binary_8");
+
+    let expected = BYTES
+        .chunks(2)
+        .map(|pair| u16::from_ne_bytes([pair[0], pair[1]]));
+    assert!(F16S
+        .iter()
+        .map(|value| value.to_bits())
+        .eq(expected.clone()));
+    assert!(BF16S.iter().map(|value| value.to_bits()).eq(expected));
+}
+
+#[test]
+fn bad_use() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/bad/include_half/*.rs");
+}
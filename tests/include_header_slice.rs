@@ -0,0 +1,51 @@
+#![allow(clippy::modulo_one)]
+
+use include_data::include_header_slice;
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+struct Header {
+    magic: [u8; 4],
+    count: u32,
+}
+
+// Safety: `Header` has no padding and only `AnyBitPattern` fields.
+unsafe impl bytemuck::Zeroable for Header {}
+unsafe impl bytemuck::AnyBitPattern for Header {}
+
+// The fixture is little-endian, and its count is checked at compile time.
+#[cfg(target_endian = "little")]
+#[test]
+fn header_slice() {
+    static TABLE: (&Header, &[u32]) =
+        include_header_slice!(Header, u32, "test_data/header_slice", count = count);
+    const UNCHECKED: (&Header, &[u16]) =
+        include_header_slice!(Header, u16, "test_data/header_slice");
+
+    let (header, records) = TABLE;
+    assert_eq!(&header.magic, b"HDRS");
+    assert_eq!(header.count, 3);
+    assert_eq!(records, [10, 20, 30]);
+    assert_eq!(records.as_ptr().align_offset(4), 0);
+
+    assert_eq!(UNCHECKED.1.len(), 6);
+}
+
+#[test]
+fn padding_after_header() {
+    // The slice starts at the next offset aligned for `u32`, skipping bytes 2
+    // and 3.
+    static PADDED: (&[u8; 2], &[u32]) = include_header_slice!([u8; 2], u32, "test_data/binary_32");
+    static HEADER_ONLY: (&u64, &[u8]) = include_header_slice!(u64, u8, "test_data/binary_8");
+
+    assert_eq!(PADDED.0, &[0, 1]);
+    assert_eq!(PADDED.1.len(), 7);
+    assert_eq!(PADDED.1[0].to_ne_bytes(), [4, 5, 6, 7]);
+    assert!(HEADER_ONLY.1.is_empty());
+}
+
+#[test]
+fn bad_use() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/bad/include_header_slice/*.rs");
+}
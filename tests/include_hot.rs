@@ -0,0 +1,144 @@
+#![allow(clippy::modulo_one)]
+
+use include_data::{include_data, include_slice, HotData, HotSlice};
+
+static TABLE: HotSlice<u32> = include_slice!(u32, "test_data/binary_32", hot);
+static VALUE: HotData<[u16; 2]> = include_data!("test_data/binary_4", hot);
+
+/// A temporary file with the given contents, and a leaked path to it.
+fn temp_file(name: &str, contents: &[u8]) -> &'static str {
+    let path = std::path::Path::new(env!("CARGO_TARGET_TMPDIR")).join(name);
+    std::fs::write(&path, contents).unwrap();
+    Box::leak(
+        path.into_os_string()
+            .into_string()
+            .unwrap()
+            .into_boxed_str(),
+    )
+}
+
+#[test]
+fn include() {
+    static PLAIN: &[u32] = include_data::include_u32s!("test_data/binary_32");
+    const PLAIN_VALUE: [u16; 2] = include_data!("test_data/binary_4");
+
+    assert_eq!(*TABLE.current(), *PLAIN);
+    assert_eq!(TABLE.current().as_ptr().align_offset(4), 0);
+    assert_eq!(*VALUE.current(), PLAIN_VALUE);
+    assert!(TABLE.take_error().is_none());
+
+    // Paths are relative to this file at runtime, too
+    assert!(TABLE.path().is_file());
+    assert!(TABLE.path().ends_with("tests/test_data/binary_32"));
+    assert!(!TABLE.reload().unwrap());
+    assert!(!VALUE.reload().unwrap());
+}
+
+#[test]
+fn from_root() {
+    static ENV: HotSlice<u8> = include_slice!(
+        u8,
+        "tests/test_data/binary_4",
+        from = env("CARGO_MANIFEST_DIR"),
+        hot,
+    );
+    static MANIFEST: HotData<u32> = include_data!("tests/test_data/binary_4", from = manifest, hot);
+
+    assert_eq!(*ENV.current(), [0, 1, 2, 3]);
+    assert!(ENV.path().is_file());
+    assert_eq!(*MANIFEST.current(), u32::from_ne_bytes([0, 1, 2, 3]));
+}
+
+#[cfg(debug_assertions)]
+#[test]
+fn reload_slice() {
+    let path = temp_file("hot_slice", &[1, 0, 2, 0]);
+    let hot = HotSlice::<u16>::new(path, &[]);
+
+    // The file is read when the data is first used
+    let first = hot.current();
+    assert_eq!(*first, [1, 2].map(u16::from_le));
+    assert!(!hot.reload().unwrap());
+
+    std::fs::write(path, [3, 0, 4, 0, 5, 0]).unwrap();
+    assert!(hot.reload().unwrap());
+    assert_eq!(*hot.current(), [3, 4, 5].map(u16::from_le));
+    // Earlier snapshots do not change
+    assert_eq!(*first, [1, 2].map(u16::from_le));
+
+    // Invalid files are reported and ignored
+    std::fs::write(path, [6, 0, 7]).unwrap();
+    assert_eq!(
+        hot.reload().unwrap_err().kind(),
+        std::io::ErrorKind::InvalidData
+    );
+    assert_eq!(*hot.current(), [3, 4, 5].map(u16::from_le));
+    std::fs::remove_file(path).unwrap();
+    assert_eq!(
+        hot.reload().unwrap_err().kind(),
+        std::io::ErrorKind::NotFound
+    );
+    assert_eq!(*hot.current(), [3, 4, 5].map(u16::from_le));
+}
+
+#[cfg(debug_assertions)]
+#[test]
+fn reload_data() {
+    static EMBEDDED: u32 = 7;
+
+    let path = temp_file("hot_data", &[0; 3]);
+    let hot = HotData::new(path, &EMBEDDED);
+    assert!(hot.reload().is_err());
+    assert_eq!(*hot.current(), 7);
+
+    std::fs::write(path, 9_u32.to_ne_bytes()).unwrap();
+    assert!(hot.reload().unwrap());
+    assert_eq!(*hot.current(), 9);
+
+    std::fs::write(path, [0; 8]).unwrap();
+    assert!(hot.reload().is_err());
+    assert_eq!(*hot.current(), 9);
+}
+
+#[cfg(debug_assertions)]
+#[test]
+fn automatic() {
+    let path = temp_file("hot_automatic", &[1]);
+    let hot = HotSlice::<u8>::new(path, &[]);
+    assert_eq!(*hot.current(), [1]);
+
+    // Changing the length is always detected as a change
+    for len in 2..=4 {
+        std::fs::write(path, vec![len; len.into()]).unwrap();
+        std::thread::sleep(HotSlice::<u8>::CHECK_INTERVAL);
+        assert_eq!(*hot.current(), *vec![len; len.into()]);
+    }
+
+    // Failures keep the previous version, and are reported once
+    std::fs::remove_file(path).unwrap();
+    std::thread::sleep(HotSlice::<u8>::CHECK_INTERVAL);
+    assert_eq!(*hot.current(), [4; 4]);
+    assert_eq!(
+        hot.take_error().unwrap().kind(),
+        std::io::ErrorKind::NotFound
+    );
+    assert!(hot.take_error().is_none());
+}
+
+#[cfg(not(debug_assertions))]
+#[test]
+fn no_reload() {
+    static EMBEDDED: u32 = 7;
+
+    let path = temp_file("hot_release", &9_u32.to_ne_bytes());
+    let hot = HotData::new(path, &EMBEDDED);
+    assert_eq!(*hot.current(), 7);
+    assert!(!hot.reload().unwrap());
+    assert_eq!(*hot.current(), 7);
+}
+
+#[test]
+fn bad_use() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/bad/include_hot/*.rs");
+}
@@ -0,0 +1,145 @@
+#![allow(clippy::modulo_one)]
+
+use embedded_graphics::image::{GetPixel, ImageRaw};
+use embedded_graphics::pixelcolor::raw::{BigEndian, LittleEndian, RawU16};
+use embedded_graphics::pixelcolor::{
+    Bgr565, Bgr888, BinaryColor, Gray2, Gray4, Gray8, Rgb555, Rgb565, Rgb666, Rgb888,
+};
+use embedded_graphics::prelude::*;
+use include_data::{include_image_raw, include_netpbm};
+
+/// Every pixel of `image`, row by row.
+fn pixels<I: GetPixel + OriginDimensions>(image: &I) -> Vec<I::Color> {
+    let size = image.size();
+    (0..size.height as i32)
+        .flat_map(|y| (0..size.width as i32).map(move |x| Point::new(x, y)))
+        .map(|point| image.pixel(point).unwrap())
+        .collect()
+}
+
+const RGB: [(u8, u8, u8); 6] = [
+    (255, 0, 0),
+    (0, 255, 0),
+    (0, 0, 255),
+    (255, 255, 255),
+    (10, 20, 30),
+    (100, 150, 200),
+];
+
+/// The expected pixels of `image_3x2.ppm`, converted by `embedded-graphics`.
+fn expected_rgb<C: From<Rgb888>>() -> Vec<C> {
+    RGB.iter()
+        .map(|&(r, g, b)| C::from(Rgb888::new(r, g, b)))
+        .collect()
+}
+
+#[test]
+fn raw() {
+    const WIDTH: u32 = 4;
+    static BIG: ImageRaw<Rgb565> = include_image_raw!(Rgb565, WIDTH, "test_data/binary_32");
+    static EXPLICIT: ImageRaw<Rgb565, BigEndian> =
+        include_image_raw!(Rgb565, WIDTH, "test_data/binary_32", endian = big);
+    static LITTLE: ImageRaw<Rgb565, LittleEndian> =
+        include_image_raw!(Rgb565, WIDTH, "test_data/binary_32", endian = little);
+    static GRAY: ImageRaw<Gray8> =
+        include_image_raw!(Gray8, 8, "tests/test_data/binary_32", from = manifest);
+    // Each row of 12 pixels is padded to 2 bytes.
+    const BINARY: ImageRaw<BinaryColor> = include_image_raw!(BinaryColor, 12, "test_data/binary_4");
+
+    assert_eq!(BIG.size(), Size::new(4, 4));
+    assert_eq!(BIG, EXPLICIT);
+    assert_eq!(LITTLE.size(), Size::new(4, 4));
+    for (i, (big, little)) in pixels(&BIG).into_iter().zip(pixels(&LITTLE)).enumerate() {
+        let bytes = [2 * i as u8, 2 * i as u8 + 1];
+        assert_eq!(big, Rgb565::from(RawU16::new(u16::from_be_bytes(bytes))));
+        assert_eq!(little, Rgb565::from(RawU16::new(u16::from_le_bytes(bytes))));
+    }
+
+    assert_eq!(GRAY.size(), Size::new(8, 4));
+    assert_eq!(pixels(&GRAY), (0..32).map(Gray8::new).collect::<Vec<_>>());
+
+    assert_eq!(BINARY.size(), Size::new(12, 2));
+    let bytes = [0u8, 1, 2, 3];
+    for (i, pixel) in pixels(&BINARY).into_iter().enumerate() {
+        let (x, y) = (i % 12, i / 12);
+        let bit = bytes[y * 2 + x / 8] >> (7 - x % 8) & 1;
+        assert_eq!(pixel, BinaryColor::from(bit == 1));
+    }
+}
+
+#[test]
+fn netpbm_rgb() {
+    static RGB565: ImageRaw<Rgb565> = include_netpbm!(Rgb565, "test_data/image_3x2.ppm");
+    static RGB565_LE: ImageRaw<Rgb565, LittleEndian> =
+        include_netpbm!(Rgb565, "test_data/image_3x2.ppm", endian = little);
+    const RGB555: ImageRaw<Rgb555> = include_netpbm!(Rgb555, "test_data/image_3x2.ppm");
+    const RGB666: ImageRaw<Rgb666> = include_netpbm!(Rgb666, "test_data/image_3x2.ppm");
+    static RGB888: ImageRaw<Rgb888> =
+        include_netpbm!(Rgb888, "tests/test_data/image_3x2.ppm", from = manifest);
+    static BGR565: ImageRaw<Bgr565, LittleEndian> = include_netpbm!(
+        Bgr565,
+        "tests/test_data/image_3x2.ppm",
+        from = manifest,
+        endian = little,
+    );
+    const BGR888: ImageRaw<Bgr888> = include_netpbm!(Bgr888, "test_data/image_3x2.ppm");
+
+    assert_eq!(RGB565.size(), Size::new(3, 2));
+    assert_eq!(pixels(&RGB565), expected_rgb::<Rgb565>());
+    assert_eq!(pixels(&RGB565_LE), expected_rgb::<Rgb565>());
+    assert_eq!(pixels(&RGB555), expected_rgb::<Rgb555>());
+    assert_eq!(pixels(&RGB666), expected_rgb::<Rgb666>());
+    assert_eq!(pixels(&RGB888), expected_rgb::<Rgb888>());
+    assert_eq!(pixels(&BGR565), expected_rgb::<Bgr565>());
+    assert_eq!(pixels(&BGR888), expected_rgb::<Bgr888>());
+}
+
+#[test]
+fn netpbm_gray() {
+    // The image has 16-bit samples with a maximum value of 1023.
+    fn expected(max: u32) -> Vec<u8> {
+        [0, 1023, 512, 300]
+            .iter()
+            .map(|value| ((value * max + 511) / 1023) as u8)
+            .collect()
+    }
+
+    static GRAY8: ImageRaw<Gray8> = include_netpbm!(Gray8, "test_data/image_2x2.pgm");
+    static GRAY4: ImageRaw<Gray4> = include_netpbm!(Gray4, "test_data/image_2x2.pgm");
+    static GRAY2: ImageRaw<Gray2> = include_netpbm!(Gray2, "test_data/image_2x2.pgm");
+
+    assert_eq!(GRAY8.size(), Size::new(2, 2));
+    let luma = |pixels: Vec<_>| pixels.iter().map(GrayColor::luma).collect::<Vec<_>>();
+    assert_eq!(luma(pixels(&GRAY8)), expected(255));
+    assert_eq!(
+        pixels(&GRAY4).iter().map(Gray4::luma).collect::<Vec<_>>(),
+        expected(15)
+    );
+    assert_eq!(
+        pixels(&GRAY2).iter().map(Gray2::luma).collect::<Vec<_>>(),
+        expected(3)
+    );
+}
+
+#[test]
+fn netpbm_binary() {
+    static BINARY: ImageRaw<BinaryColor> = include_netpbm!(BinaryColor, "test_data/image_10x2.pbm");
+
+    assert_eq!(BINARY.size(), Size::new(10, 2));
+    let on: Vec<bool> = pixels(&BINARY)
+        .into_iter()
+        .map(BinaryColor::is_on)
+        .collect();
+    let rows = ["1011000001", "0111111111"];
+    let expected: Vec<bool> = rows
+        .iter()
+        .flat_map(|row| row.chars().map(|c| c == '1'))
+        .collect();
+    assert_eq!(on, expected);
+}
+
+#[test]
+fn bad_use() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/bad/include_image/*.rs");
+}
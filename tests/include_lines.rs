@@ -0,0 +1,38 @@
+use include_data::include_lines;
+
+#[test]
+fn lines() {
+    static ALL: &[&str] = include_lines!("test_data/lines");
+    const COMMENTED: &[&str] = include_lines!("test_data/lines", comments = "#");
+    static TRIMMED: &[&str] = include_lines!("test_data/lines", count = 5, trim);
+
+    assert_eq!(
+        ALL,
+        [
+            "# Greeting",
+            "  Hello  ",
+            "World",
+            "   # indented comment",
+            "Goodbye"
+        ]
+    );
+    assert_eq!(COMMENTED, ["  Hello  ", "World", "Goodbye"]);
+    assert_eq!(TRIMMED[1], "Hello");
+    assert_eq!(TRIMMED[3], "# indented comment");
+}
+
+#[test]
+fn options() {
+    static MESSAGES: &[&str] =
+        include_lines!("test_data/lines", trim, comments = "# G", count = 4,);
+    assert_eq!(
+        MESSAGES,
+        ["Hello", "World", "# indented comment", "Goodbye"]
+    );
+}
+
+#[test]
+fn bad_use() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/bad/include_lines/*.rs");
+}
@@ -0,0 +1,53 @@
+use include_data::{include_map, StaticMap};
+
+static SHUFFLED: StaticMap<u32, u32> = include_map!(u32, u32, "test_data/map_u32_u32");
+
+#[test]
+fn lookup() {
+    static RECORDS: &[[u32; 2]] = include_data::include_slice!([u32; 2], "test_data/map_u32_u32");
+
+    assert_eq!(SHUFFLED.len(), 100);
+    for [key, value] in RECORDS {
+        assert_eq!(SHUFFLED.get(key), Some(value));
+        // The fixture is little-endian.
+        assert_eq!(u32::from_le(*value), u32::from_le(*key) * 3);
+    }
+    assert_eq!(SHUFFLED.get(&0), None);
+    assert!(!SHUFFLED.contains_key(&1_000_000));
+    assert_eq!(SHUFFLED.iter().count(), 100);
+}
+
+#[test]
+fn small_maps() {
+    const BYTES: StaticMap<u8, u8> = include_map!(u8, u8, "test_data/binary_2");
+    // Every size of tree, with complete and incomplete last levels.
+    static SIZES: [StaticMap<u8, [u8; 0]>; 5] = [
+        include_map!(u8, [u8; 0], "test_data/binary_2"),
+        include_map!(u8, [u8; 0], "test_data/binary_4"),
+        include_map!(u8, [u8; 0], "test_data/binary_8"),
+        include_map!(u8, [u8; 0], "test_data/binary_31"),
+        include_map!(u8, [u8; 0], "test_data/binary_64"),
+    ];
+
+    assert_eq!(BYTES.len(), 1);
+    assert_eq!(BYTES.get(&0), Some(&1));
+    assert_eq!(BYTES.get(&1), None);
+    for map in &SIZES {
+        for key in 0..64 {
+            assert_eq!(map.contains_key(&key), usize::from(key) < map.len());
+        }
+    }
+}
+
+#[test]
+fn empty() {
+    static EMPTY: StaticMap<u64, u64> = include_map!(u64, u64, "test_data/empty");
+    assert!(EMPTY.is_empty());
+    assert_eq!(EMPTY.get(&0), None);
+}
+
+#[test]
+fn bad_use() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/bad/include_map/*.rs");
+}
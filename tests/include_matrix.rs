@@ -0,0 +1,43 @@
+#![allow(clippy::modulo_one)]
+
+use include_data::{include_matrix, include_shaped};
+
+static ROWS: &[[u8; 8]] = include_matrix!(u8, cols = 8, "test_data/binary_32");
+const EXACT: &[[u32; 2]] = include_matrix!(u32, rows = 4, cols = 2, "test_data/binary_32");
+
+#[test]
+fn matrix() {
+    static WORDS: &[u32] = include_data::include_u32s!("test_data/binary_32");
+
+    assert_eq!(ROWS.len(), 4);
+    for (i, row) in ROWS.iter().enumerate() {
+        for (j, &value) in row.iter().enumerate() {
+            assert_eq!(usize::from(value), i * 8 + j);
+        }
+    }
+
+    assert_eq!(EXACT.len(), 4);
+    assert_eq!(EXACT.concat(), WORDS);
+    assert_eq!(EXACT.as_ptr().align_offset(4), 0);
+}
+
+#[test]
+fn shaped() {
+    static VOLUME: &[[[u8; 4]; 2]] = include_shaped!(u8, [_, 2, 4], "test_data/binary_64");
+    const CUBE: &[[[u16; 2]; 2]] = include_shaped!(u16, [8, 2, 2], "test_data/binary_64");
+    static FLAT: &[u16] = include_shaped!(u16, [_], "test_data/binary_4");
+    static FLAT_EXACT: &[u16] = include_shaped!(u16, [2], "test_data/binary_4");
+
+    assert_eq!(VOLUME.len(), 8);
+    assert_eq!(VOLUME[7][1], [60, 61, 62, 63]);
+    assert_eq!(CUBE.len(), 8);
+    assert_eq!(CUBE.as_ptr().align_offset(2), 0);
+    assert_eq!(FLAT, FLAT_EXACT);
+    assert_eq!(FLAT.len(), 2);
+}
+
+#[test]
+fn bad_use() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/bad/include_matrix/*.rs");
+}
@@ -0,0 +1,87 @@
+#![allow(clippy::modulo_one)]
+
+use include_data::{include_ndarray, include_npy, StaticArray};
+use ndarray::{ArrayView1, ArrayView2, ArrayView3};
+
+#[test]
+fn given_shape() {
+    static GRID: StaticArray<u16, 2> = include_ndarray!(u16, [4, 4], "test_data/binary_32");
+    const CUBE: StaticArray<u8, 3> =
+        include_ndarray!(u8, [2, 3, 2], "tests/test_data/binary_12", from = manifest);
+    static FLAT: StaticArray<u32, 1> = include_ndarray!(u32, [8], "test_data/binary_32");
+    static EMPTY: StaticArray<u64, 2> = include_ndarray!(u64, [0, 3], "test_data/empty");
+
+    let grid: ArrayView2<'static, u16> = GRID.view();
+    assert_eq!(grid.dim(), (4, 4));
+    assert_eq!(GRID.shape(), [4, 4]);
+    assert!(!GRID.is_fortran_order());
+    for ((row, column), &value) in grid.indexed_iter() {
+        let first = (row * 8 + column * 2) as u8;
+        assert_eq!(value, u16::from_ne_bytes([first, first + 1]));
+    }
+
+    let cube: ArrayView3<'static, u8> = CUBE.view();
+    assert_eq!(cube.dim(), (2, 3, 2));
+    assert_eq!(cube[[1, 2, 0]], 10);
+    assert_eq!(CUBE.as_slice(), &[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11]);
+
+    let flat: ArrayView1<'static, u32> = FLAT.view();
+    assert_eq!(flat.len(), 8);
+    assert_eq!(FLAT.len(), 8);
+    assert_eq!(flat[7], u32::from_ne_bytes([28, 29, 30, 31]));
+
+    assert!(EMPTY.is_empty());
+    assert_eq!(EMPTY.view().dim(), (0, 3));
+}
+
+#[test]
+fn new() {
+    const DATA: &[i32] = &[1, 2, 3, 4, 5, 6];
+    const ROWS: StaticArray<i32, 2> = StaticArray::new(DATA, [2, 3]);
+    const COLUMNS: StaticArray<i32, 2> = StaticArray::new_fortran_order(DATA, [2, 3]);
+
+    assert_eq!(ROWS.view(), ndarray::arr2(&[[1, 2, 3], [4, 5, 6]]));
+    assert!(COLUMNS.is_fortran_order());
+    assert_eq!(COLUMNS.view(), ndarray::arr2(&[[1, 3, 5], [2, 4, 6]]));
+    assert_eq!(COLUMNS.as_slice(), DATA);
+}
+
+#[test]
+fn npy() {
+    static MATRIX: StaticArray<f32, 2> = include_npy!(f32, "test_data/matrix_f32_2x3.npy");
+    static COLUMNS: StaticArray<i32, 2> = include_npy!(i32, "test_data/fortran_i32_3x2.npy");
+    const CUBE: StaticArray<u8, 3> =
+        include_npy!(u8, "tests/test_data/cube_u8_2x2x2.npy", from = manifest);
+    static VECTOR: StaticArray<f64, 1> = include_npy!(f64, "test_data/vector_f64_4.npy");
+
+    assert_eq!(
+        MATRIX.view(),
+        ndarray::arr2(&[[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]])
+    );
+    assert!(!MATRIX.is_fortran_order());
+
+    assert_eq!(COLUMNS.shape(), [3, 2]);
+    assert!(COLUMNS.is_fortran_order());
+    assert_eq!(COLUMNS.as_slice(), &[-3, -2, -1, 0, 1, 2]);
+    assert_eq!(COLUMNS.view(), ndarray::arr2(&[[-3, 0], [-2, 1], [-1, 2]]));
+
+    assert_eq!(
+        CUBE.view(),
+        ndarray::arr3(&[[[0, 1], [2, 3]], [[4, 5], [6, 7]]])
+    );
+
+    assert_eq!(VECTOR.view(), ndarray::arr1(&[0.5, -1.0, 2.25, 1e100]));
+    assert_eq!(
+        VECTOR
+            .as_slice()
+            .as_ptr()
+            .align_offset(core::mem::align_of::<f64>()),
+        0
+    );
+}
+
+#[test]
+fn bad_use() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/bad/include_ndarray/*.rs");
+}
@@ -0,0 +1,68 @@
+use include_data::{include_packed, include_u12s, include_u24s, include_u4s, Endian, PackedSlice};
+
+static BINARY_64: &[u8] = include_bytes!("test_data/binary_64");
+
+/// Read value `index` of width `bits` one bit at a time.
+fn reference(bytes: &[u8], bits: usize, index: usize, endian: Endian) -> u32 {
+    let mut value = 0;
+    for i in 0..bits {
+        let bit = index * bits + i;
+        let (shift, target) = match endian {
+            Endian::Little => (bit % 8, i),
+            Endian::Big => (7 - bit % 8, bits - 1 - i),
+        };
+        value |= u32::from(bytes[bit / 8] >> shift & 1) << target;
+    }
+    value
+}
+
+#[test]
+fn all_widths() {
+    for bits in 1..=32 {
+        for endian in [Endian::Little, Endian::Big] {
+            let packed = PackedSlice::<u32>::new(BINARY_64, bits, endian);
+            assert_eq!(packed.len(), 512 / bits as usize);
+            for (i, value) in packed.iter().enumerate() {
+                assert_eq!(value, reference(BINARY_64, bits as usize, i, endian));
+            }
+        }
+    }
+}
+
+#[test]
+fn aliases() {
+    static NIBBLES: PackedSlice<u8> = include_u4s!("test_data/binary_4", endian = little);
+    static SAMPLES: PackedSlice<u16> = include_u12s!("test_data/binary_12", endian = little);
+    static WIDE: PackedSlice<u32> = include_u24s!("test_data/binary_12", endian = big);
+
+    assert!(NIBBLES.iter().eq([0, 0, 1, 0, 2, 0, 3, 0]));
+    assert_eq!(SAMPLES.len(), 8);
+    assert_eq!(SAMPLES.get(0), Some(0x100));
+    assert_eq!(SAMPLES.get(1), Some(0x020));
+    assert_eq!(SAMPLES.get(8), None);
+    assert!(WIDE.iter().eq([0x00_0102, 0x03_0405, 0x06_0708, 0x09_0a0b]));
+    assert_eq!(WIDE.iter().rev().next(), Some(0x09_0a0b));
+}
+
+#[test]
+fn native_and_len() {
+    const NATIVE: PackedSlice<u32> = include_u24s!("test_data/binary_12");
+    static EXACT: PackedSlice<u32> =
+        include_packed!(u32, bits = 31, "test_data/binary_31", len = 8);
+    static SHORTER: PackedSlice<u8> = include_u4s!("test_data/binary_4", len = 7);
+
+    assert_eq!(NATIVE.endian(), Endian::NATIVE);
+    assert_eq!(EXACT.len(), 8);
+    assert_eq!(EXACT.bits(), 31);
+    assert_eq!(SHORTER.len(), 7);
+    assert_eq!(
+        format!("{SHORTER:?}"),
+        format!("{:?}", SHORTER.iter().collect::<Vec<_>>())
+    );
+}
+
+#[test]
+fn bad_use() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/bad/include_packed/*.rs");
+}
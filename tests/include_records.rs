@@ -0,0 +1,66 @@
+use include_data::{include_records, RecordFormat, Records};
+
+const TERMINATED: Records = include_records!(terminator = b'\0', "test_data/records_nul");
+
+// The fixture has little-endian length prefixes, which are checked at compile
+// time.
+#[cfg(target_endian = "little")]
+#[test]
+fn length_prefixed() {
+    static PREFIXED: Records = include_records!(prefix = u32, "test_data/records_u32");
+    static INDEXED: Records = include_records!(prefix = u32, "test_data/records_u32", indexed);
+
+    let expected: [&[u8]; 4] = [b"one", b"four", b"", b"fives"];
+
+    for records in [PREFIXED, INDEXED] {
+        assert_eq!(records.len(), 4);
+        assert_eq!(records.format(), RecordFormat::PrefixU32);
+        assert!(records.iter().eq(expected));
+        for (i, record) in expected.iter().enumerate() {
+            assert_eq!(records.get(i), Some(*record));
+        }
+        assert_eq!(records.get(4), None);
+    }
+
+    assert!(!PREFIXED.is_indexed());
+    assert!(INDEXED.is_indexed());
+}
+
+#[test]
+fn terminated() {
+    static LINES: Records = include_records!(terminator = b'\0', "test_data/records_nul", indexed);
+
+    let expected: [&[u8]; 4] = [b"alpha", b"beta", b"", b"gamma"];
+
+    assert_eq!(TERMINATED.len(), 4);
+    assert!(TERMINATED.iter().eq(expected));
+    assert_eq!(TERMINATED.iter().len(), 4);
+    assert_eq!(TERMINATED.get(3), Some(&b"gamma"[..]));
+
+    assert!(LINES.into_iter().eq(expected));
+    assert_eq!(LINES.get(1), Some(&b"beta"[..]));
+}
+
+#[test]
+fn other_prefixes() {
+    const BYTES: Records = Records::new(b"\x01a\x00\x02bc", RecordFormat::PrefixU8);
+    assert!(BYTES.iter().eq([&b"a"[..], b"", b"bc"]));
+
+    let wide = Records::new(
+        if cfg!(target_endian = "little") {
+            b"\x03\x00ab\x00"
+        } else {
+            b"\x00\x03ab\x00"
+        },
+        RecordFormat::PrefixU16,
+    );
+    assert!(wide.iter().eq([&b"ab\x00"[..]]));
+    let widest = Records::new(&[0; 8], RecordFormat::PrefixU64);
+    assert!(widest.iter().eq([&b""[..]]));
+}
+
+#[test]
+fn bad_use() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/bad/include_records/*.rs");
+}
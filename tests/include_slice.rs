@@ -86,3 +86,19 @@ mod noincs {
         assert_eq!(INTS.len(), 8);
     }
 }
+
+#[test]
+fn path_roots() {
+    static MANIFEST: &[u16] = include_slice!(u16, "tests/test_data/binary_32", from = manifest);
+    static WORKSPACE: &[u16] = include_u16s!("tests/test_data/binary_32", from = workspace);
+    static ENV: &[u16] = include_slice!(
+        u16,
+        "tests/test_data/binary_32",
+        from = env("CARGO_MANIFEST_DIR"),
+    );
+    static RELATIVE: &[u16] = include_slice!(u16, "test_data/binary_32");
+
+    assert_eq!(MANIFEST, RELATIVE);
+    assert_eq!(WORKSPACE, RELATIVE);
+    assert_eq!(ENV, RELATIVE);
+}
@@ -0,0 +1,51 @@
+#![allow(clippy::modulo_one)]
+
+use include_data::{include_concat_bounds, include_slice_concat};
+
+#[test]
+fn concat_u32() {
+    static TABLE: &[u32] = include_slice_concat!(
+        u32,
+        [
+            "test_data/binary_4",
+            "test_data/binary_8",
+            "test_data/binary_32",
+        ],
+    );
+    const BOUNDS: [usize; 4] = include_concat_bounds!(
+        u32,
+        [
+            "test_data/binary_4",
+            "test_data/binary_8",
+            "test_data/binary_32",
+        ],
+    );
+    static PARTS: [&[u32]; 3] = [
+        include_data::include_u32s!("test_data/binary_4"),
+        include_data::include_u32s!("test_data/binary_8"),
+        include_data::include_u32s!("test_data/binary_32"),
+    ];
+
+    assert_eq!(TABLE.as_ptr().align_offset(4), 0);
+    assert_eq!(TABLE.len(), 11);
+    assert_eq!(BOUNDS, [0, 1, 3, 11]);
+    for (i, part) in PARTS.iter().enumerate() {
+        assert_eq!(&TABLE[BOUNDS[i]..BOUNDS[i + 1]], *part);
+    }
+}
+
+#[test]
+fn concat_single() {
+    static TABLE: &[u64] = include_slice_concat!(u64, ["test_data/binary_64"]);
+    static PLAIN: &[u64] = include_data::include_u64s!("test_data/binary_64");
+
+    assert_eq!(TABLE.as_ptr().align_offset(8), 0);
+    assert_eq!(TABLE, PLAIN);
+    assert_eq!(include_concat_bounds!(u64, ["test_data/binary_64"]), [0, 8]);
+}
+
+#[test]
+fn bad_use() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/bad/include_slice_concat/*.rs");
+}
@@ -0,0 +1,60 @@
+#![allow(clippy::modulo_one)]
+
+use include_data::{include_sorted_slice, SortedSlice};
+
+static BYTES: SortedSlice<u8> = include_sorted_slice!(u8, "test_data/binary_32", strict);
+static SIGNED: SortedSlice<i16> = include_sorted_slice!(i16, "test_data/sorted_i16", strict);
+const FLOATS: SortedSlice<f32> = include_sorted_slice!(f32, "test_data/sorted_f32");
+
+#[test]
+fn searches() {
+    assert_eq!(BYTES.len(), 32);
+    assert_eq!(BYTES.binary_search(&12), Ok(12));
+    assert_eq!(BYTES.binary_search(&40), Err(32));
+    assert!(BYTES.contains(&31));
+    assert_eq!(BYTES.lower_bound(&5), 5);
+    assert_eq!(BYTES.upper_bound(&5), 6);
+    assert_eq!(BYTES.range(&4..&8), [4, 5, 6, 7]);
+    assert_eq!(BYTES.range(&4..=&8), [4, 5, 6, 7, 8]);
+    assert_eq!(BYTES.range(&30..), [30, 31]);
+    assert_eq!(BYTES.range(..), BYTES.as_slice());
+    assert!(BYTES.range(&8..&4).is_empty());
+
+    assert_eq!(SIGNED.as_slice(), [-300, -1, 0, 5, 1000]);
+    assert_eq!(SIGNED.binary_search(&-1), Ok(1));
+    assert_eq!(SIGNED.binary_search(&-2), Err(1));
+    assert_eq!(SIGNED.range(&-100..&100), [-1, 0, 5]);
+}
+
+#[test]
+fn floats() {
+    assert_eq!(FLOATS.len(), 6);
+    assert_eq!(FLOATS[0], f32::NEG_INFINITY);
+    assert_eq!(FLOATS.lower_bound(&0.0), 2);
+    assert_eq!(FLOATS.upper_bound(&-0.0), 4);
+    assert!(!FLOATS.contains(&f32::NAN));
+}
+
+#[test]
+fn arrays() {
+    static PAIRS: SortedSlice<[u8; 2]> =
+        include_sorted_slice!([u8; 2], "test_data/binary_32", strict);
+
+    assert_eq!(PAIRS.binary_search(&[6, 7]), Ok(3));
+    assert_eq!(PAIRS.binary_search(&[6, 6]), Err(3));
+}
+
+#[test]
+fn runtime() {
+    static SORTED: [u32; 3] = [1, 1, 2];
+    static UNSORTED: [u32; 3] = [1, 0, 2];
+
+    assert!(SortedSlice::new(&SORTED).is_some());
+    assert!(SortedSlice::new(&UNSORTED).is_none());
+}
+
+#[test]
+fn bad_use() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/bad/include_sorted_slice/*.rs");
+}
@@ -0,0 +1,106 @@
+use include_data::{
+    include_typed, typed_checksum, write_typed, Endian, TypedHeader, TypedHeaderError,
+};
+
+#[cfg(target_endian = "little")]
+#[test]
+fn include_u32() {
+    static TYPED: &[u32] = include_typed!(u32, "test_data/typed_u32");
+    static PLAIN: &[u32] = include_data::include_slice!(u32, "test_data/binary_32");
+
+    assert_eq!(TYPED.as_ptr().align_offset(4), 0);
+    assert_eq!(TYPED, PLAIN);
+}
+
+#[cfg(target_endian = "little")]
+#[test]
+fn round_trip() {
+    static FILE: &[u8] = include_bytes!("test_data/typed_u32");
+    static PLAIN: &[u32] = include_data::include_slice!(u32, "test_data/binary_32");
+
+    let mut written = Vec::new();
+    write_typed(PLAIN, |bytes| {
+        written.extend_from_slice(bytes);
+        Ok::<(), ()>(())
+    })
+    .unwrap();
+    assert_eq!(written, FILE);
+
+    let header = TypedHeader::parse(FILE).unwrap();
+    assert_eq!(header, TypedHeader::for_slice(PLAIN));
+    assert_eq!(header.endian, Endian::Little);
+    assert_eq!(header.count, 8);
+    assert_eq!(header.checksum, typed_checksum(TypedHeader::payload(FILE)));
+    assert_eq!(header.validate::<u32>(TypedHeader::payload(FILE)), Ok(()));
+}
+
+#[test]
+fn parse_errors() {
+    let data = [1_u16, 2, 3];
+    let header = TypedHeader::for_slice(&data).to_bytes();
+
+    assert_eq!(
+        TypedHeader::parse(&header[..47]),
+        Err(TypedHeaderError::TooShort)
+    );
+
+    let mut bad = header;
+    bad[0] = b'X';
+    assert_eq!(TypedHeader::parse(&bad), Err(TypedHeaderError::Magic));
+
+    let mut bad = header;
+    bad[8] = 2;
+    assert_eq!(TypedHeader::parse(&bad), Err(TypedHeaderError::Version));
+
+    let mut bad = header;
+    bad[10] = 2;
+    assert_eq!(TypedHeader::parse(&bad), Err(TypedHeaderError::Endian));
+
+    let mut bad = header;
+    bad[47] = 1;
+    assert_eq!(TypedHeader::parse(&bad), Err(TypedHeaderError::Reserved));
+}
+
+#[test]
+fn validate_errors() {
+    let data = [1_u16, 2, 3];
+    let payload: &[u8] = bytemuck::cast_slice(&data);
+    let header = TypedHeader::for_slice(&data);
+
+    assert_eq!(header.validate::<u16>(payload), Ok(()));
+    assert_eq!(
+        header.validate::<u32>(payload),
+        Err(TypedHeaderError::ElementSize)
+    );
+    assert_eq!(
+        header.validate::<i16>(payload),
+        Err(TypedHeaderError::TypeId)
+    );
+    assert_eq!(
+        header.validate::<u16>(&payload[..4]),
+        Err(TypedHeaderError::Count)
+    );
+    assert_eq!(
+        header.validate::<u16>(&[0; 6]),
+        Err(TypedHeaderError::Checksum)
+    );
+
+    let foreign = TypedHeader {
+        endian: match Endian::NATIVE {
+            Endian::Little => Endian::Big,
+            Endian::Big => Endian::Little,
+        },
+        ..header
+    };
+    assert_eq!(
+        foreign.validate::<u16>(payload),
+        Err(TypedHeaderError::ForeignEndian)
+    );
+}
+
+#[cfg(target_endian = "little")]
+#[test]
+fn bad_use() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/bad/include_typed/*.rs");
+}
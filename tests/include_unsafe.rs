@@ -1,4 +1,4 @@
-use include_data::include_unsafe;
+use include_data::{include_unsafe, LayoutFingerprint};
 
 #[test]
 fn with_padding() {
@@ -39,8 +39,80 @@ fn include_as_const() {
     }
 }
 
+#[test]
+fn fingerprint_argument() {
+    #[repr(C)]
+    #[derive(LayoutFingerprint)]
+    struct StructWithBool {
+        boolean: bool,
+        two_bytes: u16,
+    }
+
+    static BAR_DATA: StructWithBool = unsafe {
+        include_unsafe!(
+            "test_data/file_exactly_4_bytes_long",
+            fingerprint = StructWithBool::FINGERPRINT,
+        )
+    };
+
+    assert!(BAR_DATA.boolean);
+}
+
+#[test]
+fn fingerprint_header() {
+    #[repr(C)]
+    #[derive(LayoutFingerprint)]
+    struct StructWithBool {
+        boolean: bool,
+        two_bytes: u16,
+    }
+
+    const BAR_DATA: StructWithBool = unsafe {
+        include_unsafe!(
+            "test_data/fingerprinted_struct_with_bool",
+            fingerprint_header
+        )
+    };
+
+    assert!(BAR_DATA.boolean);
+    if cfg!(target_endian = "little") {
+        assert_eq!(BAR_DATA.two_bytes, 0x0302);
+    } else {
+        assert_eq!(BAR_DATA.two_bytes, 0x0203);
+    }
+}
+
+#[test]
+fn path_roots() {
+    #[repr(C)]
+    #[derive(LayoutFingerprint)]
+    struct StructWithBool {
+        boolean: bool,
+        two_bytes: u16,
+    }
+
+    const MANIFEST: [u16; 2] =
+        unsafe { include_unsafe!("tests/test_data/binary_4", from = manifest) };
+    const HEADER: StructWithBool = unsafe {
+        include_unsafe!(
+            "tests/test_data/fingerprinted_struct_with_bool",
+            from = workspace,
+            fingerprint_header,
+        )
+    };
+
+    assert_eq!(
+        MANIFEST,
+        [u16::from_ne_bytes([0, 1]), u16::from_ne_bytes([2, 3])]
+    );
+    assert!(HEADER.boolean);
+}
+
 #[test]
 fn bad_use() {
     let t = trybuild::TestCases::new();
     t.compile_fail("tests/bad/include_unsafe/*.rs");
+    // A passing case makes trybuild build rather than check, which is needed
+    // for the fingerprint check in `let` position to be evaluated.
+    t.pass("tests/good/include_unsafe/*.rs");
 }
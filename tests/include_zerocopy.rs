@@ -0,0 +1,60 @@
+#![allow(clippy::modulo_one)]
+
+use include_data::{include_data, include_slice};
+use zerocopy::{FromBytes, Immutable, KnownLayout};
+
+#[derive(Debug, PartialEq, FromBytes, Immutable, KnownLayout)]
+#[repr(C)]
+struct Pair {
+    first: u16,
+    second: u16,
+}
+
+#[test]
+fn data() {
+    static PAIR: Pair = include_data!("test_data/file_exactly_4_bytes_long", via = zerocopy);
+    const FROM_MANIFEST: Pair = include_data!(
+        "tests/test_data/file_exactly_4_bytes_long",
+        from = manifest,
+        via = zerocopy,
+    );
+    const INTEGER: u32 = include_data!("test_data/file_exactly_4_bytes_long", via = zerocopy);
+    const BYTEMUCK: u32 = include_data!("test_data/file_exactly_4_bytes_long", via = bytemuck);
+
+    let expected = Pair {
+        first: u16::from_ne_bytes([1, 0]),
+        second: u16::from_ne_bytes([2, 3]),
+    };
+    assert_eq!(PAIR, expected);
+    assert_eq!(FROM_MANIFEST, expected);
+    assert_eq!(INTEGER, u32::from_ne_bytes([1, 0, 2, 3]));
+    assert_eq!(INTEGER, BYTEMUCK);
+}
+
+#[test]
+fn slice() {
+    static PAIRS: &[Pair] = include_slice!(Pair, "test_data/binary_32", via = zerocopy);
+    static FROM_MANIFEST: &[Pair] = include_slice!(
+        Pair,
+        "tests/test_data/binary_32",
+        from = manifest,
+        via = zerocopy,
+    );
+    static BYTEMUCK: &[u16] = include_slice!(u16, "test_data/binary_32", via = bytemuck);
+
+    assert_eq!(PAIRS.len(), 8);
+    assert_eq!(PAIRS.as_ptr() as usize % core::mem::align_of::<Pair>(), 0);
+    for (i, pair) in PAIRS.iter().enumerate() {
+        let first = 4 * i as u8;
+        assert_eq!(pair.first, u16::from_ne_bytes([first, first + 1]));
+        assert_eq!(pair.second, u16::from_ne_bytes([first + 2, first + 3]));
+        assert_eq!(pair.first, BYTEMUCK[2 * i]);
+    }
+    assert_eq!(FROM_MANIFEST, PAIRS);
+}
+
+#[test]
+fn bad_use() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/bad/include_zerocopy/*.rs");
+}
@@ -0,0 +1,79 @@
+#![allow(clippy::modulo_one)]
+
+use glam::{Affine3A, Mat3A, Mat4, Vec3, Vec3A};
+use include_data::{include_mint, include_padded, include_slice};
+use nalgebra::{Matrix4, Point3};
+
+fn expected(i: usize) -> [f32; 3] {
+    if i == 1 {
+        [-1.0, 0.5, 2.0]
+    } else {
+        let first = 3.0 * i as f32;
+        [first, first + 1.0, first + 2.0]
+    }
+}
+
+#[test]
+fn glam() {
+    static VEC3S: &[Vec3] = include_slice!(Vec3, "test_data/vec3_f32");
+    static MAT4S: &[Mat4] = include_slice!(Mat4, "test_data/binary_64");
+
+    assert_eq!(VEC3S.len(), 12);
+    for (i, value) in VEC3S.iter().enumerate() {
+        assert_eq!(value.to_array(), expected(i));
+    }
+    assert_eq!(MAT4S.len(), 1);
+}
+
+#[test]
+fn padded() {
+    static VEC3AS: &[Vec3A] = include_padded!(Vec3A, "test_data/vec3_f32");
+    static MAT3AS: &[Mat3A] = include_padded!(Mat3A, "tests/test_data/vec3_f32", from = manifest);
+    const AFFINES: &[Affine3A] = include_padded!(Affine3A, "test_data/vec3_f32");
+
+    assert_eq!(VEC3AS.len(), 12);
+    for (i, value) in VEC3AS.iter().enumerate() {
+        assert_eq!(value.to_array(), expected(i));
+    }
+
+    assert_eq!(MAT3AS.len(), 4);
+    assert_eq!(MAT3AS[1].x_axis.to_array(), expected(3));
+    assert_eq!(MAT3AS[1].z_axis.to_array(), expected(5));
+
+    assert_eq!(AFFINES.len(), 3);
+    assert_eq!(AFFINES[0].matrix3.y_axis.to_array(), expected(1));
+    assert_eq!(AFFINES[2].translation.to_array(), expected(11));
+}
+
+#[test]
+fn nalgebra() {
+    static POINTS: &[Point3<f32>] = include_slice!(Point3<f32>, "test_data/vec3_f32");
+    static MATRICES: &[Matrix4<f32>] = include_slice!(Matrix4<f32>, "test_data/binary_64");
+
+    assert_eq!(POINTS.len(), 12);
+    assert_eq!(POINTS[1], Point3::new(-1.0, 0.5, 2.0));
+    assert_eq!(MATRICES.len(), 1);
+}
+
+#[test]
+fn mint() {
+    static VECTORS: &[mint::Vector3<f32>] = include_mint!(mint::Vector3<f32>, "test_data/vec3_f32");
+    static MATRICES: &[mint::ColumnMatrix3<f32>] =
+        include_mint!(mint::ColumnMatrix3<f32>, "test_data/vec3_f32");
+    static EULERS: &[mint::EulerAngles<f32, mint::IntraXYZ>] =
+        include_mint!(mint::EulerAngles<f32, mint::IntraXYZ>, "test_data/vec3_f32");
+
+    assert_eq!(VECTORS.len(), 12);
+    for (i, value) in VECTORS.iter().enumerate() {
+        assert_eq!(<[f32; 3]>::from(*value), expected(i));
+    }
+    assert_eq!(MATRICES.len(), 4);
+    assert_eq!(<[f32; 3]>::from(MATRICES[0].y), expected(1));
+    assert_eq!(EULERS[1].b, 0.5);
+}
+
+#[test]
+fn bad_use() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/bad/linalg/*.rs");
+}
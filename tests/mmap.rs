@@ -0,0 +1,47 @@
+use include_data::{include_slice, IncludedSlice, MmapError};
+
+static BINARY_32: &[u32] = include_slice!(u32, "test_data/binary_32");
+
+fn test_data(name: &str) -> std::path::PathBuf {
+    std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/test_data")
+        .join(name)
+}
+
+#[test]
+fn embedded() {
+    let slice = IncludedSlice::embedded(BINARY_32);
+
+    assert!(!slice.is_mapped());
+    assert_eq!(&*slice, BINARY_32);
+}
+
+#[test]
+fn mapped() {
+    let slice: IncludedSlice<u32> = unsafe { IncludedSlice::map(test_data("binary_32")).unwrap() };
+
+    assert!(slice.is_mapped());
+    assert_eq!(slice.as_ptr().align_offset(4), 0);
+    assert_eq!(&*slice, BINARY_32);
+}
+
+#[test]
+fn mapped_empty() {
+    let path = std::path::Path::new(env!("CARGO_TARGET_TMPDIR")).join("mmap_empty");
+    std::fs::write(&path, []).unwrap();
+
+    let slice: IncludedSlice<u64> = unsafe { IncludedSlice::map(path).unwrap() };
+    assert!(slice.is_empty());
+}
+
+#[test]
+fn errors() {
+    let wrong_size = unsafe { IncludedSlice::<u32>::map(test_data("binary_31")) };
+    assert!(matches!(wrong_size, Err(MmapError::Size)));
+
+    let missing = unsafe { IncludedSlice::<u32>::map(test_data("non-existent")) };
+    assert!(matches!(missing, Err(MmapError::Io(_))));
+
+    let next_to_exe = unsafe { IncludedSlice::<u32>::map_next_to_exe("non-existent") };
+    assert!(matches!(next_to_exe, Err(MmapError::Io(_))));
+}